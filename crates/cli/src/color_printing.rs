@@ -1,13 +1,14 @@
 use std::io::Write;
 
-use palette::color_difference::Wcag21RelativeContrast;
 use palette::rgb::{Rgb, Rgba};
-use palette::{IntoColor, WithAlpha};
+use palette::WithAlpha;
 use termcolor::{ColorSpec, StandardStream, WriteColor};
 
+use color_utils::contrast::best_readable_background;
+use color_utils::named_colors::nearest_named_color;
 use color_utils::to_str::{
-	to_hsl_function_str, to_hwb_function_str, to_rgb_function_str, to_rgb_hex_str, ChannelUnit,
-	LetterCase, OmitAlphaChannel, ShorthandNotation,
+	to_css_string_with_precision, to_rgb_legacy_function_str_with_precision, CssColorFormat,
+	OmitAlphaChannel, MAX_DECIMAL_PRECISION,
 };
 
 use crate::options::ColorFormat;
@@ -17,96 +18,92 @@ fn rgb_as_term_color(color: Rgb) -> termcolor::Color {
 	termcolor::Color::Rgb(converted.red, converted.green, converted.blue)
 }
 
-/// Finds and returns the `color_options` value that has the best contrast to `initial_color`.
-fn get_best_contrast<'a>(initial_color: &'a Rgb, color_options: &'a [Rgb]) -> &'a Rgb {
-	let mut best_contrast_ratio: f32 = 0.0;
-	// Default value only matters if all options have zero contrast, so they should be the same as initial_color anyway.
-	let mut best_contrast_ratio_color = initial_color;
-
-	for color_option in color_options {
-		let contrast_ratio = initial_color.relative_contrast(*color_option);
-		if contrast_ratio > best_contrast_ratio {
-			best_contrast_ratio = contrast_ratio;
-			best_contrast_ratio_color = color_option;
+/// Formats the color as string in the given format, with at most `precision` decimal places for each value.
+// TODO: Allow customization of formatting flags.
+pub fn format_color(color: &Rgba, format: ColorFormat, precision: u8) -> String {
+	let css_color_format = match format {
+		ColorFormat::Auto | ColorFormat::RgbHex => CssColorFormat::Hex,
+		ColorFormat::RgbFunction => CssColorFormat::RgbFunction,
+		ColorFormat::RgbLegacyFunction => {
+			return to_rgb_legacy_function_str_with_precision(
+				color,
+				OmitAlphaChannel::IfOpaque,
+				precision,
+			);
 		}
-	}
-
-	best_contrast_ratio_color
+		ColorFormat::HslFunction => CssColorFormat::HslFunction,
+		ColorFormat::HwbFunction => CssColorFormat::HwbFunction,
+		ColorFormat::LabFunction => CssColorFormat::LabFunction,
+		ColorFormat::OklabFunction => CssColorFormat::OklabFunction,
+		ColorFormat::LchFunction => CssColorFormat::LchFunction,
+		ColorFormat::OklchFunction => CssColorFormat::OklchFunction,
+		ColorFormat::DisplayP3Function => CssColorFormat::DisplayP3Function,
+		ColorFormat::Named => {
+			return String::from(nearest_named_color(&color.without_alpha()).name());
+		}
+	};
+	to_css_string_with_precision(
+		color,
+		css_color_format,
+		OmitAlphaChannel::IfOpaque,
+		precision,
+	)
 }
 
-// TODO: Allow customization of formatting flags.
-fn format_color(color: &Rgba, format: ColorFormat) -> String {
-	match format {
-		ColorFormat::Auto => to_rgb_hex_str(
-			&color.into_format(),
-			OmitAlphaChannel::IfOpaque,
-			ShorthandNotation::IfPossible,
-			LetterCase::Uppercase,
-		),
-		ColorFormat::RgbHex => to_rgb_hex_str(
-			&color.into_format(),
-			OmitAlphaChannel::IfOpaque,
-			ShorthandNotation::IfPossible,
-			LetterCase::Uppercase,
-		),
-		ColorFormat::RgbFunction => to_rgb_function_str(
-			color,
-			OmitAlphaChannel::IfOpaque,
-			ChannelUnit::Number,
-			ChannelUnit::Number,
-		),
-		ColorFormat::HslFunction => to_hsl_function_str(
-			&(*color).into_color(),
-			OmitAlphaChannel::IfOpaque,
-			ChannelUnit::Number,
-		),
-		ColorFormat::HwbFunction => to_hwb_function_str(
-			&(*color).into_color(),
-			OmitAlphaChannel::IfOpaque,
-			ChannelUnit::Number,
-		),
+/// Formats a number like a contrast or a difference with `precision` decimal places.
+/// Precisions above [`MAX_DECIMAL_PRECISION`] format the number without rounding.
+pub fn format_decimal(val: f64, precision: u8) -> String {
+	if precision > MAX_DECIMAL_PRECISION {
+		val.to_string()
+	} else {
+		format!("{:.*}", usize::from(precision), val)
 	}
 }
 
-const BLACK: Rgb = Rgb::new(0.0, 0.0, 0.0);
-const WHITE: Rgb = Rgb::new(1.0, 1.0, 1.0);
-const FOREGROUND_COLOR_OPTIONS: [palette::rgb::Rgb; 2] = [BLACK, WHITE];
-
 /// Prints colored color value to stream. Stream color is reset afterward.
 pub fn print_color(
 	stdout: &mut StandardStream,
 	color: &Rgba,
 	format: ColorFormat,
+	precision: u8,
 ) -> std::io::Result<()> {
 	let opaque_color = color.without_alpha();
 
-	let foreground_color = get_best_contrast(&opaque_color, &FOREGROUND_COLOR_OPTIONS);
+	let foreground_color = best_readable_background(color).without_alpha();
 
 	stdout.set_color(
 		ColorSpec::new()
 			.set_bg(Some(rgb_as_term_color(opaque_color.into_format())))
 			.set_fg(Some(rgb_as_term_color(foreground_color.into_format()))),
 	)?;
-	write!(stdout, "{}", format_color(color, format))?;
+	write!(stdout, "{}", format_color(color, format, precision))?;
 	stdout.set_color(&ColorSpec::default())
 }
 
 #[cfg(test)]
 mod tests {
+	use color_utils::to_str::FULL_DECIMAL_PRECISION;
+
 	use super::*;
 
 	#[test]
-	fn get_best_contrast_finds_result() {
-		let black = Rgb::from_components((0.0, 0.0, 0.0));
-		let white = Rgb::from_components((1.0, 1.0, 1.0));
-		let options = [black, white];
-
-		let bright_color = Rgb::from_components((0.9, 0.85, 1.0));
-		let bright_color_best_contrast_actual = get_best_contrast(&bright_color, &options);
-		assert_eq!(*bright_color_best_contrast_actual, BLACK);
+	fn format_color_respects_precision() {
+		let color = Rgba::new(0.5, 0.0, 0.0, 1.0);
+
+		assert_eq!(
+			format_color(&color, ColorFormat::RgbFunction, 0),
+			"rgb(128 0 0)"
+		);
+		assert_eq!(
+			format_color(&color, ColorFormat::RgbFunction, FULL_DECIMAL_PRECISION),
+			"rgb(127.5 0 0)"
+		);
+	}
 
-		let dark_color = Rgb::from_components((0.0, 0.1, 0.25));
-		let dark_color_best_contrast_actual = get_best_contrast(&dark_color, &options);
-		assert_eq!(*dark_color_best_contrast_actual, WHITE);
+	#[test]
+	fn format_decimal_respects_precision() {
+		assert_eq!(format_decimal(0.2126, 2), "0.21");
+		assert_eq!(format_decimal(0.2126, 0), "0");
+		assert_eq!(format_decimal(0.2126, FULL_DECIMAL_PRECISION), "0.2126");
 	}
 }
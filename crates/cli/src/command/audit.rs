@@ -0,0 +1,219 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use palette::rgb::Rgba;
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::contrast::{contrast_ratio_levels_reached_srgba, ContrastChecker, ContrastLevel};
+use color_utils::parser::parse_color;
+
+use crate::color_printing::print_color;
+use crate::options::Options;
+
+/// Text and background color declared in the same rule block.
+#[derive(Debug, PartialEq, Clone)]
+struct ColorPair {
+	/// Line of the `color` declaration, starting at 1.
+	line: usize,
+	color: Rgba,
+	background_color: Rgba,
+}
+
+/// Last parsable color declaration of a property in a rule block, with its line.
+type Declaration = Option<(usize, Rgba)>;
+
+/// Finds the `color` and `background-color` declarations of each rule block.
+/// Only blocks declaring both are returned. Values that are not colors, e.g. `inherit`, are ignored.
+/// Nested blocks are treated as separate blocks. Comments, strings and parentheses (e.g. of `url()`) are skipped,
+/// so that braces and semicolons in them do not end a declaration.
+fn find_color_pairs(css: &str) -> Vec<ColorPair> {
+	let mut pairs = Vec::new();
+	let mut color: Declaration = None;
+	let mut background_color: Declaration = None;
+
+	let mut line = 1;
+	let mut segment_start_line = 1;
+	let mut segment = String::new();
+	let mut depth: usize = 0;
+	let mut chars = css.chars().peekable();
+	while let Some(character) = chars.next() {
+		match character {
+			'/' if chars.peek() == Some(&'*') => {
+				chars.next();
+				let mut previous = ' ';
+				for comment_character in chars.by_ref() {
+					if comment_character == '\n' {
+						line += 1;
+					}
+					if previous == '*' && comment_character == '/' {
+						break;
+					}
+					previous = comment_character;
+				}
+				continue;
+			}
+			'"' | '\'' => {
+				segment.push(character);
+				let mut escaped = false;
+				for string_character in chars.by_ref() {
+					segment.push(string_character);
+					if string_character == '\n' {
+						line += 1;
+					}
+					if escaped {
+						escaped = false;
+					} else if string_character == '\\' {
+						escaped = true;
+					} else if string_character == character {
+						break;
+					}
+				}
+				continue;
+			}
+			'{' if depth == 0 => {
+				color = None;
+				background_color = None;
+				segment.clear();
+			}
+			';' | '}' if depth == 0 => {
+				if let Some((property, value)) = segment.split_once(':') {
+					if let Ok(parsed) = parse_color(value.trim()) {
+						match property.trim().to_ascii_lowercase().as_str() {
+							"color" => color = Some((segment_start_line, parsed)),
+							"background-color" => {
+								background_color = Some((segment_start_line, parsed))
+							}
+							_ => {}
+						}
+					}
+				}
+				segment.clear();
+				if character == '}' {
+					if let (Some((color_line, color)), Some((_, background_color))) =
+						(color, background_color)
+					{
+						pairs.push(ColorPair {
+							line: color_line,
+							color,
+							background_color,
+						});
+					}
+					color = None;
+					background_color = None;
+				}
+			}
+			_ => {
+				if segment.trim().is_empty() {
+					segment_start_line = line;
+				}
+				match character {
+					'(' => depth += 1,
+					')' => depth = depth.saturating_sub(1),
+					_ => {}
+				}
+				segment.push(character);
+			}
+		}
+		if character == '\n' {
+			line += 1;
+		}
+	}
+	pairs
+}
+
+/// Prints each pair of text and background color in the CSS file that does not reach the level.
+/// Returns if all pairs reach the level.
+///
+/// Translucent colors are composited on a white page first,
+/// see [`ContrastChecker::with_simulated_alpha_on_white`].
+pub fn print_audit(file: &Path, level: ContrastLevel, options: &Options) -> std::io::Result<bool> {
+	let pairs = find_color_pairs(&fs::read_to_string(file)?);
+
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+	let mut failed_count = 0;
+	for pair in &pairs {
+		let composited =
+			ContrastChecker::new(pair.color, pair.background_color).with_simulated_alpha_on_white();
+		let report =
+			contrast_ratio_levels_reached_srgba(&composited.foreground, &composited.background);
+		if report.levels.contains(&level) {
+			continue;
+		}
+		failed_count += 1;
+
+		write!(out, "{}:{}: ", file.display(), pair.line)?;
+		print_color(&mut out, &pair.color, options.format, options.precision)?;
+		write!(out, " on ")?;
+		print_color(
+			&mut out,
+			&pair.background_color,
+			options.format,
+			options.precision,
+		)?;
+		writeln!(
+			out,
+			": {}, does not reach {}.",
+			report.ratio_formatted_with_precision(options.precision),
+			level.short_name()
+		)?;
+	}
+
+	if failed_count == 0 {
+		writeln!(
+			out,
+			"All {} color pair(s) reach {}.",
+			pairs.len(),
+			level.short_name()
+		)?;
+	} else {
+		writeln!(
+			out,
+			"{} of {} color pair(s) do not reach {}.",
+			failed_count,
+			pairs.len(),
+			level.short_name()
+		)?;
+	}
+	Ok(failed_count == 0)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn find_color_pairs_finds_pairs_in_same_block() {
+		let css = "a {\n\tcolor: #000;\n}\n\n.button {\n\t/* Brand\n\tcolors */\n\tbackground-color: #fff;\n\tColor: black;\n}\n";
+
+		assert_eq!(
+			find_color_pairs(css),
+			vec![ColorPair {
+				line: 9,
+				color: Rgba::new(0.0, 0.0, 0.0, 1.0),
+				background_color: Rgba::new(1.0, 1.0, 1.0, 1.0),
+			}]
+		);
+	}
+
+	#[test]
+	fn find_color_pairs_ignores_non_colors() {
+		let css = "a { color: inherit; background-color: #fff; }\nb:hover { color: var(--x); background-color: red }";
+
+		assert!(find_color_pairs(css).is_empty());
+	}
+
+	#[test]
+	fn find_color_pairs_skips_strings_and_urls() {
+		let css = "a {\n\tbackground: url(data:image/png;base64,AA==}) no-repeat;\n\tcontent: \"{;}\";\n\tbackground-image: url(\"x;y\");\n\tcolor: #000;\n\tbackground-color: #fff;\n}\n";
+
+		assert_eq!(
+			find_color_pairs(css),
+			vec![ColorPair {
+				line: 5,
+				color: Rgba::new(0.0, 0.0, 0.0, 1.0),
+				background_color: Rgba::new(1.0, 1.0, 1.0, 1.0),
+			}]
+		);
+	}
+}
@@ -0,0 +1,34 @@
+use std::io::Write;
+
+use palette::rgb::Rgba;
+use palette::WithAlpha;
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::blend::blend;
+
+use crate::color_printing::print_color;
+use crate::options::{value_name, BlendModeArg, Options};
+
+pub fn print_blend(
+	base_color: &Rgba,
+	blend_color: &Rgba,
+	mode: BlendModeArg,
+	options: &Options,
+) -> std::io::Result<()> {
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+
+	let blended = blend(
+		&base_color.without_alpha(),
+		&blend_color.without_alpha(),
+		mode.into(),
+	)
+	.with_alpha(base_color.alpha);
+
+	write!(out, "Blend of ")?;
+	print_color(&mut out, blend_color, options.format, options.precision)?;
+	write!(out, " onto ")?;
+	print_color(&mut out, base_color, options.format, options.precision)?;
+	write!(out, " with {} is ", value_name(&mode))?;
+	print_color(&mut out, &blended, options.format, options.precision)?;
+	writeln!(out, ".")
+}
@@ -0,0 +1,21 @@
+use std::io::Write;
+
+use palette::rgb::Rgba;
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::operations::rotate_hue;
+
+use crate::color_printing::print_color;
+use crate::options::Options;
+
+pub fn print_complement(color: &Rgba, angle: f32, options: &Options) -> std::io::Result<()> {
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+
+	let complement = rotate_hue(color, angle);
+
+	write!(out, "Complement of ")?;
+	print_color(&mut out, color, options.format, options.precision)?;
+	write!(out, " at {} degrees is ", angle)?;
+	print_color(&mut out, &complement, options.format, options.precision)?;
+	writeln!(out, ".")
+}
@@ -1,70 +1,101 @@
-use core::fmt;
 use std::collections::HashSet;
-use std::fmt::Display;
-use std::io::Write;
+use std::fs;
+use std::io::{Error, ErrorKind, Write};
+use std::path::Path;
 
-use palette::color_difference::Wcag21RelativeContrast;
-use palette::rgb::{Rgb, Rgba};
+use palette::rgb::Rgba;
+use serde::Serialize;
 use termcolor::{ColorChoice, StandardStream};
 
-use crate::color_printing::print_color;
-use crate::options::Options;
+use color_utils::contrast::{
+	apca_contrast, contrast_ratio_levels_reached, ApcaLevel, ContrastLevel,
+};
+use color_utils::parser::{parse_color_unclamped, split_outside_parentheses};
 
-/// Contrast target values based on
-/// <https://www.w3.org/TR/2008/REC-WCAG20-20081211/#visual-audio-contrast-contrast>.
-#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-enum ContrastLevel {
-	/// Enhanced contrast for text.
-	Aaa,
+use crate::color_parser::clamp_to_srgb_gamut;
+use crate::color_printing::{format_color, format_decimal, print_color};
+use crate::json_printing::print_json;
+use crate::options::{ContrastType, Options};
 
-	/// Enhanced contrast for large text.
-	LargeAaa,
-
-	/// Minimum contrast for text.
-	Aa,
+/// Gets the levels applying to the content type, or all levels if none is given, sorted.
+fn filter_levels(
+	levels: HashSet<ContrastLevel>,
+	contrast_type: Option<ContrastType>,
+) -> Vec<ContrastLevel> {
+	let levels = hash_set_as_sorted_vec(levels);
+	match contrast_type {
+		Some(contrast_type) => levels
+			.into_iter()
+			.filter(|level| contrast_type.includes(level))
+			.collect(),
+		None => levels,
+	}
+}
 
-	/// Minimum contrast for large text.
-	LargeAa,
+fn hash_set_as_sorted_vec<T: Ord>(hash_set: HashSet<T>) -> Vec<T> {
+	let mut set_copy_vec = hash_set.into_iter().collect::<Vec<_>>();
+	set_copy_vec.sort();
+	set_copy_vec
 }
 
-impl Display for ContrastLevel {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		f.write_str(match &self {
-			ContrastLevel::Aaa => "AAA",
-			ContrastLevel::LargeAaa => "AAA (Large Text)",
-			ContrastLevel::Aa => "AA",
-			ContrastLevel::LargeAa => "AA (Large Text)",
-		})
-	}
+#[derive(Debug, Serialize)]
+struct ContrastJson {
+	ratio: f32,
+	ratio_formatted: String,
+	levels_reached: Vec<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	apca_contrast: Option<f64>,
 }
 
-fn contrast_ratio_levels_reached(color_1: &Rgb, color_2: &Rgb) -> HashSet<ContrastLevel> {
-	let mut reached = HashSet::with_capacity(4);
-	if color_1.has_min_contrast_large_text(*color_2) {
-		reached.insert(ContrastLevel::LargeAa);
-		if color_1.has_min_contrast_text(*color_2) {
-			reached.insert(ContrastLevel::Aa);
-			reached.insert(ContrastLevel::LargeAaa);
-			if color_1.has_enhanced_contrast_text(*color_2) {
-				reached.insert(ContrastLevel::Aaa);
-			}
+impl ContrastJson {
+	fn new(
+		color_1: &Rgba,
+		color_2: &Rgba,
+		wcag3: bool,
+		contrast_type: Option<ContrastType>,
+		precision: u8,
+	) -> Self {
+		let report = contrast_ratio_levels_reached(color_1, color_2);
+		ContrastJson {
+			ratio: report.ratio,
+			ratio_formatted: report.ratio_formatted_with_precision(precision),
+			levels_reached: filter_levels(report.levels, contrast_type)
+				.iter()
+				.map(|level| String::from(level.short_name()))
+				.collect(),
+			apca_contrast: wcag3.then(|| apca_contrast(color_1, color_2)),
 		}
 	}
-	reached
 }
 
-fn hash_set_as_sorted_vec<T: Ord>(hash_set: HashSet<T>) -> Vec<T> {
-	let mut set_copy_vec = hash_set.into_iter().collect::<Vec<_>>();
-	set_copy_vec.sort();
-	set_copy_vec
-}
+pub fn print_contrast(
+	color_1: &Rgba,
+	color_2: &Rgba,
+	wcag3: bool,
+	verbose: bool,
+	contrast_type: Option<ContrastType>,
+	options: &Options,
+) -> std::io::Result<()> {
+	if options.json {
+		return print_json(&ContrastJson::new(
+			color_1,
+			color_2,
+			wcag3,
+			contrast_type,
+			options.precision,
+		));
+	}
 
-pub fn print_contrast(color_1: &Rgba, color_2: &Rgba, options: &Options) -> std::io::Result<()> {
 	let mut out = StandardStream::stdout(ColorChoice::Auto);
 
 	print_contrast_ratio(&mut out, color_1, color_2, options)?;
 
-	print_contrast_levels_reached(&mut out, color_1, color_2)
+	print_contrast_levels_reached(&mut out, color_1, color_2, verbose, contrast_type)?;
+
+	if wcag3 {
+		print_apca_contrast(&mut out, color_1, color_2, options)?;
+	}
+	Ok(())
 }
 
 fn print_contrast_ratio(
@@ -74,26 +105,43 @@ fn print_contrast_ratio(
 	options: &Options,
 ) -> std::io::Result<()> {
 	write!(out, "WCAG 2.0 AA/AAA contrast ratio for ")?;
-	print_color(out, color_1, options.format)?;
+	print_color(out, color_1, options.format, options.precision)?;
 	write!(out, " to ")?;
-	print_color(out, color_2, options.format)?;
+	print_color(out, color_2, options.format, options.precision)?;
 
-	let contrast_ratio = color_1.relative_contrast(**color_2);
-	writeln!(out, " is {:.2}.", contrast_ratio)
+	let report = contrast_ratio_levels_reached(color_1, color_2);
+	writeln!(
+		out,
+		" is {}.",
+		report.ratio_formatted_with_precision(options.precision)
+	)
+}
+
+fn format_contrast_level(level: &ContrastLevel, verbose: bool) -> String {
+	if verbose {
+		level.to_string()
+	} else {
+		String::from(level.short_name())
+	}
 }
 
 fn print_contrast_levels_reached(
 	out: &mut StandardStream,
 	color_1: &Rgba,
 	color_2: &Rgba,
+	verbose: bool,
+	contrast_type: Option<ContrastType>,
 ) -> std::io::Result<()> {
-	let contrast_levels_reached = contrast_ratio_levels_reached(color_1, color_2);
+	let contrast_levels_reached = filter_levels(
+		contrast_ratio_levels_reached(color_1, color_2).levels,
+		contrast_type,
+	);
 	let contrast_levels_reached_str: String = if contrast_levels_reached.is_empty() {
 		String::from("None")
 	} else {
-		hash_set_as_sorted_vec(contrast_levels_reached)
+		contrast_levels_reached
 			.iter()
-			.map(std::string::ToString::to_string)
+			.map(|level| format_contrast_level(level, verbose))
 			.collect::<Vec<String>>()
 			.join(", ")
 	};
@@ -103,3 +151,191 @@ fn print_contrast_levels_reached(
 		contrast_levels_reached_str
 	)
 }
+
+fn print_apca_contrast(
+	out: &mut StandardStream,
+	text_color: &Rgba,
+	background_color: &Rgba,
+	options: &Options,
+) -> std::io::Result<()> {
+	write!(out, "WCAG 3.0 draft APCA contrast for text ")?;
+	print_color(out, text_color, options.format, options.precision)?;
+	write!(out, " on background ")?;
+	print_color(out, background_color, options.format, options.precision)?;
+
+	let lightness_contrast = apca_contrast(text_color, background_color);
+	writeln!(
+		out,
+		" is Lc {}.",
+		format_decimal(lightness_contrast, options.precision)
+	)?;
+
+	writeln!(
+		out,
+		"APCA level reached: {}.",
+		ApcaLevel::for_contrast(lightness_contrast)
+			.map_or_else(|| String::from("None"), |level| level.to_string())
+	)
+}
+
+#[derive(Debug, Serialize)]
+struct ContrastPairJson {
+	color: String,
+	other_color: String,
+	#[serde(flatten)]
+	contrast: ContrastJson,
+	min_level_reached: bool,
+}
+
+/// Splits a line into two colors at whitespace outside of parentheses,
+/// so that function notations like `rgb(255 0 0)` stay intact.
+fn split_color_pair(line: &str) -> Option<(&str, &str)> {
+	let colors = split_outside_parentheses(line, char::is_whitespace)
+		.into_iter()
+		.filter(|color| !color.is_empty())
+		.collect::<Vec<_>>();
+	match colors[..] {
+		[color, other_color] => Some((color, other_color)),
+		_ => None,
+	}
+}
+
+fn parse_color_pairs(content: &str) -> std::io::Result<Vec<(Rgba, Rgba)>> {
+	content
+		.lines()
+		.enumerate()
+		.map(|(i, line)| (i + 1, line.trim()))
+		.filter(|(_, line)| !line.is_empty())
+		.map(|(line_number, line)| {
+			let invalid_data = |msg: String| {
+				Error::new(
+					ErrorKind::InvalidData,
+					format!("Line {}: {}", line_number, msg),
+				)
+			};
+			let (color, other_color) = split_color_pair(line)
+				.ok_or_else(|| invalid_data(String::from("Expected two colors.")))?;
+			Ok((
+				parse_color_unclamped(color).map_err(|err| invalid_data(err.to_string()))?,
+				parse_color_unclamped(other_color).map_err(|err| invalid_data(err.to_string()))?,
+			))
+		})
+		.collect()
+}
+
+/// Prints the contrast of each pair of colors in the file.
+/// Colors outside of the sRGB gamut are clamped, see [`clamp_to_srgb_gamut`].
+/// Returns if all pairs reach the minimum level.
+pub fn print_contrast_file(
+	file: &Path,
+	min_level: ContrastLevel,
+	verbose: bool,
+	warn_gamut: bool,
+	options: &Options,
+) -> std::io::Result<bool> {
+	let mut pairs = parse_color_pairs(&fs::read_to_string(file)?)?;
+	for (color, other_color) in &mut pairs {
+		clamp_to_srgb_gamut(color, warn_gamut, options);
+		clamp_to_srgb_gamut(other_color, warn_gamut, options);
+	}
+
+	let reports = pairs
+		.iter()
+		.map(|(color, other_color)| contrast_ratio_levels_reached(color, other_color))
+		.collect::<Vec<_>>();
+	let reached = reports
+		.iter()
+		.map(|report| report.levels.contains(&min_level))
+		.collect::<Vec<_>>();
+
+	if options.json {
+		let json = pairs
+			.iter()
+			.zip(&reached)
+			.map(
+				|((color, other_color), min_level_reached)| ContrastPairJson {
+					color: format_color(color, options.format, options.precision),
+					other_color: format_color(other_color, options.format, options.precision),
+					contrast: ContrastJson::new(color, other_color, false, None, options.precision),
+					min_level_reached: *min_level_reached,
+				},
+			)
+			.collect::<Vec<_>>();
+		print_json(&json)?;
+	} else {
+		let mut out = StandardStream::stdout(ColorChoice::Auto);
+		for (((color, other_color), report), min_level_reached) in
+			pairs.iter().zip(&reports).zip(&reached)
+		{
+			print_color(&mut out, color, options.format, options.precision)?;
+			write!(out, " to ")?;
+			print_color(&mut out, other_color, options.format, options.precision)?;
+			writeln!(
+				out,
+				": {}, {} {}.",
+				report.ratio_formatted_with_precision(options.precision),
+				if *min_level_reached {
+					"reaches"
+				} else {
+					"does not reach"
+				},
+				format_contrast_level(&min_level, verbose)
+			)?;
+		}
+	}
+
+	Ok(reached.iter().all(|min_level_reached| *min_level_reached))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn split_color_pair_splits_at_whitespace() {
+		assert_eq!(split_color_pair("#FFF  #000"), Some(("#FFF", "#000")));
+	}
+
+	#[test]
+	fn split_color_pair_keeps_functions() {
+		assert_eq!(
+			split_color_pair("rgb(255 0 0) hsl(0 100% 50%)"),
+			Some(("rgb(255 0 0)", "hsl(0 100% 50%)"))
+		);
+	}
+
+	#[test]
+	fn split_color_pair_requires_two_colors() {
+		assert_eq!(split_color_pair("rgb(255 0 0)"), None);
+		assert_eq!(split_color_pair("#FFF "), None);
+		assert_eq!(split_color_pair("#FFF #000 #777"), None);
+	}
+
+	#[test]
+	fn filter_levels_by_type() {
+		let levels = HashSet::from([
+			ContrastLevel::Aa,
+			ContrastLevel::LargeAa,
+			ContrastLevel::UiComponent,
+			ContrastLevel::Focus,
+		]);
+
+		assert_eq!(
+			filter_levels(levels.clone(), Some(ContrastType::Text)),
+			vec![ContrastLevel::Aa, ContrastLevel::LargeAa]
+		);
+		assert_eq!(
+			filter_levels(levels.clone(), Some(ContrastType::Ui)),
+			vec![ContrastLevel::UiComponent]
+		);
+		assert_eq!(filter_levels(levels, None).len(), 4);
+	}
+
+	#[test]
+	fn parse_color_pairs_reports_line() {
+		let err = parse_color_pairs("#FFF #000\n\n#FFF").unwrap_err();
+
+		assert_eq!(err.kind(), ErrorKind::InvalidData);
+		assert!(err.to_string().starts_with("Line 3:"));
+	}
+}
@@ -0,0 +1,16 @@
+use palette::rgb::Rgba;
+
+use crate::color_printing::format_color;
+use crate::options::{ColorFormat, Options};
+
+/// Prints the color in the given format as a single line without terminal colors, for use in scripts.
+/// If no format is given, the global format option is used.
+pub fn print_convert(
+	color: &Rgba,
+	to: Option<ColorFormat>,
+	options: &Options,
+) -> std::io::Result<()> {
+	let format = to.unwrap_or(options.format);
+	println!("{}", format_color(color, format, options.precision));
+	Ok(())
+}
@@ -1,49 +1,192 @@
-use std::io::Write;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Error, ErrorKind, Write};
 
 use palette::rgb::Rgba;
+use serde::Serialize;
 use termcolor::{ColorChoice, StandardStream};
 
+use color_utils::contrast::relative_luminance;
+use color_utils::parser::parse_multiple_colors_unclamped;
 use color_utils::util::is_opaque;
 
-use crate::color_printing::print_color;
+use crate::color_parser::clamp_to_srgb_gamut;
+use crate::color_printing::{format_color, format_decimal, print_color};
+use crate::json_printing::print_json;
 use crate::options::{ColorFormat, Options};
+use crate::output::print_css_vars;
+
+#[derive(Debug, Serialize)]
+struct DetailsJson {
+	hex: String,
+	rgb_function: String,
+	hsl_function: String,
+	hwb_function: String,
+	lab_function: String,
+	oklab_function: String,
+	lch_function: String,
+	oklch_function: String,
+	is_opaque: bool,
+	relative_luminance: f32,
+}
+
+impl DetailsJson {
+	fn new(color: &Rgba, precision: u8) -> Self {
+		DetailsJson {
+			hex: format_color(color, ColorFormat::RgbHex, precision),
+			rgb_function: format_color(color, ColorFormat::RgbFunction, precision),
+			hsl_function: format_color(color, ColorFormat::HslFunction, precision),
+			hwb_function: format_color(color, ColorFormat::HwbFunction, precision),
+			lab_function: format_color(color, ColorFormat::LabFunction, precision),
+			oklab_function: format_color(color, ColorFormat::OklabFunction, precision),
+			lch_function: format_color(color, ColorFormat::LchFunction, precision),
+			oklch_function: format_color(color, ColorFormat::OklchFunction, precision),
+			is_opaque: is_opaque(color),
+			relative_luminance: relative_luminance(color),
+		}
+	}
+}
+
+/// Creates custom properties for the color in all formats, named after `name` with a suffix
+/// for each format except hex, e.g. `primary` and `primary-hsl`.
+fn css_vars(color: &Rgba, name: &str, precision: u8) -> HashMap<String, String> {
+	[
+		("", ColorFormat::RgbHex),
+		("-rgb", ColorFormat::RgbFunction),
+		("-hsl", ColorFormat::HslFunction),
+		("-hwb", ColorFormat::HwbFunction),
+		("-lab", ColorFormat::LabFunction),
+		("-oklab", ColorFormat::OklabFunction),
+		("-lch", ColorFormat::LchFunction),
+		("-oklch", ColorFormat::OklchFunction),
+	]
+	.into_iter()
+	.map(|(suffix, format)| {
+		(
+			format!("{}{}", name, suffix),
+			format_color(color, format, precision),
+		)
+	})
+	.collect()
+}
+
+pub fn print_details(
+	color: &Rgba,
+	css_var_name: Option<&str>,
+	options: &Options,
+) -> std::io::Result<()> {
+	if let Some(name) = css_var_name {
+		let properties = css_vars(color, name, options.precision);
+		return if options.json {
+			// Sorted by name for a stable output.
+			print_json(&properties.into_iter().collect::<BTreeMap<_, _>>())
+		} else {
+			print_css_vars(&properties)
+		};
+	}
+	if options.json {
+		return print_json(&DetailsJson::new(color, options.precision));
+	}
 
-pub fn print_details(color: &Rgba, options: &Options) -> std::io::Result<()> {
 	let mut out = StandardStream::stdout(ColorChoice::Auto);
 
 	write!(&mut out, "Details for color ")?;
-	print_color(&mut out, color, options.format)?;
+	print_color(&mut out, color, options.format, options.precision)?;
 	writeln!(&mut out, ":")?;
 	writeln!(&mut out, "-------")?;
 
-	print_general_details(&mut out, color)?;
+	print_general_details(&mut out, color, options.precision)?;
 
-	print_format_details(&mut out, color)
+	print_format_details(&mut out, color, options.precision)
 }
 
-fn print_general_details(out: &mut StandardStream, color: &Rgba) -> std::io::Result<()> {
+/// Prints the details of each color in the list, see [`parse_multiple_colors_unclamped`].
+/// Colors that cannot be parsed are reported on stderr, resulting in an error after all colors are printed.
+/// Colors outside of the sRGB gamut are clamped like color arguments, see [`clamp_to_srgb_gamut`].
+pub fn print_details_multiple(
+	seq: &str,
+	warn_gamut: bool,
+	options: &Options,
+) -> std::io::Result<()> {
+	let mut colors = Vec::new();
+	let mut error_count = 0;
+	for (i, result) in parse_multiple_colors_unclamped(seq).into_iter().enumerate() {
+		match result {
+			Ok(mut color) => {
+				clamp_to_srgb_gamut(&mut color, warn_gamut, options);
+				colors.push(color);
+			}
+			Err(err) => {
+				eprintln!("Color {}: {}", i + 1, err);
+				error_count += 1;
+			}
+		}
+	}
+
+	if options.json {
+		print_json(
+			&colors
+				.iter()
+				.map(|color| DetailsJson::new(color, options.precision))
+				.collect::<Vec<_>>(),
+		)?;
+	} else {
+		for (i, color) in colors.iter().enumerate() {
+			if i > 0 {
+				println!();
+			}
+			print_details(color, None, options)?;
+		}
+	}
+
+	if error_count > 0 {
+		return Err(Error::new(
+			ErrorKind::InvalidInput,
+			format!("{} color(s) could not be parsed.", error_count),
+		));
+	}
+	Ok(())
+}
+
+fn print_general_details(
+	out: &mut StandardStream,
+	color: &Rgba,
+	precision: u8,
+) -> std::io::Result<()> {
 	writeln!(out, "General: ")?;
-	writeln!(out, "\tIs opaque: {}.", is_opaque(color))
+	writeln!(out, "\tIs opaque: {}.", is_opaque(color))?;
+	writeln!(
+		out,
+		"\tRelative luminance: {}.",
+		format_decimal(f64::from(relative_luminance(color)), precision)
+	)
 	// TODO: output if color fits in 8 bit channel
 }
 
-fn print_format_details(out: &mut StandardStream, color: &Rgba) -> std::io::Result<()> {
+fn print_format_details(
+	out: &mut StandardStream,
+	color: &Rgba,
+	precision: u8,
+) -> std::io::Result<()> {
 	writeln!(out, "Formats: ")?;
 
 	write!(out, "\tIn RGB hexadecimal notation: ")?;
-	print_color(out, color, ColorFormat::RgbHex)?;
+	print_color(out, color, ColorFormat::RgbHex, precision)?;
 	// TODO: output if precision is lost in this form
 	writeln!(out, ".")?;
 
 	write!(out, "\tIn RGB function notation: ")?;
-	print_color(out, color, ColorFormat::RgbFunction)?;
+	print_color(out, color, ColorFormat::RgbFunction, precision)?;
 	writeln!(out, ".")?;
 
 	write!(out, "\tIn HSL function notation: ")?;
-	print_color(out, color, ColorFormat::HslFunction)?;
+	print_color(out, color, ColorFormat::HslFunction, precision)?;
 	writeln!(out, ".")?;
 
 	write!(out, "\tIn HWB function notation: ")?;
-	print_color(out, color, ColorFormat::HwbFunction)?;
+	print_color(out, color, ColorFormat::HwbFunction, precision)?;
+	writeln!(out, ".")?;
+
+	write!(out, "\tIn Oklab function notation: ")?;
+	print_color(out, color, ColorFormat::OklabFunction, precision)?;
 	writeln!(out, ".")
 }
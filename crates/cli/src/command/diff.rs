@@ -0,0 +1,73 @@
+use std::io::Write;
+
+use palette::rgb::Rgba;
+use palette::white_point::D65;
+use palette::{IntoColor, Lab, WithAlpha};
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::distance::{
+	delta_e_cie76, delta_e_cie94, delta_e_ciede2000, euclidean_rgb_distance, Cie94Params,
+	PerceptualDifference,
+};
+
+use crate::color_printing::{format_decimal, print_color};
+use crate::options::{value_name, DiffMetric, Options};
+
+/// Calculates the difference of the colors, ignoring alpha.
+fn color_difference(color_1: &Rgba, color_2: &Rgba, metric: DiffMetric) -> f32 {
+	let lab_1: Lab<D65, f32> = color_1.without_alpha().into_color();
+	let lab_2: Lab<D65, f32> = color_2.without_alpha().into_color();
+	match metric {
+		DiffMetric::Cie76 => delta_e_cie76(&lab_1, &lab_2),
+		DiffMetric::Cie94 => delta_e_cie94(&lab_1, &lab_2, Cie94Params::GRAPHIC_ARTS),
+		DiffMetric::Ciede2000 => delta_e_ciede2000(&lab_1, &lab_2),
+		DiffMetric::EuclideanRgb => {
+			euclidean_rgb_distance(&color_1.without_alpha(), &color_2.without_alpha())
+		}
+	}
+}
+
+pub fn print_diff(
+	color_1: &Rgba,
+	color_2: &Rgba,
+	metric: DiffMetric,
+	options: &Options,
+) -> std::io::Result<()> {
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+
+	let difference = color_difference(color_1, color_2, metric);
+
+	write!(out, "{} difference between ", value_name(&metric))?;
+	print_color(&mut out, color_1, options.format, options.precision)?;
+	write!(out, " and ")?;
+	print_color(&mut out, color_2, options.format, options.precision)?;
+	write!(
+		out,
+		" is {}",
+		format_decimal(f64::from(difference), options.precision)
+	)?;
+	// The qualitative scale is only defined for Delta E metrics.
+	if metric != DiffMetric::EuclideanRgb {
+		write!(out, " ({})", PerceptualDifference::for_delta_e(difference))?;
+	}
+	writeln!(out, ".")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn color_difference_same_color() {
+		let color = Rgba::new(0.2, 0.4, 0.6, 1.0);
+
+		for metric in [
+			DiffMetric::Cie76,
+			DiffMetric::Cie94,
+			DiffMetric::Ciede2000,
+			DiffMetric::EuclideanRgb,
+		] {
+			assert_eq!(color_difference(&color, &color, metric), 0.0);
+		}
+	}
+}
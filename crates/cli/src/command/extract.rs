@@ -0,0 +1,56 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use palette::rgb::Rgba;
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::parser::extract_colors_from_css;
+
+use crate::color_printing::print_color;
+use crate::options::Options;
+
+/// Counts occurrences of each distinct color, sorted by count descending.
+/// Colors with the same count keep the order in which they were first found.
+fn count_occurrences(colors: &[Rgba]) -> Vec<(Rgba, usize)> {
+	let mut counts: Vec<(Rgba, usize)> = Vec::new();
+	for color in colors {
+		match counts.iter_mut().find(|(counted, _)| counted == color) {
+			Some((_, count)) => *count += 1,
+			None => counts.push((*color, 1)),
+		}
+	}
+	counts.sort_by(|(_, count_1), (_, count_2)| count_2.cmp(count_1));
+	counts
+}
+
+pub fn print_extract(file: &Path, options: &Options) -> std::io::Result<()> {
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+
+	let css = fs::read_to_string(file)?;
+	let colors = extract_colors_from_css(&css)
+		.into_iter()
+		.map(|(_, color)| color)
+		.collect::<Vec<_>>();
+
+	for (color, count) in count_occurrences(&colors) {
+		print_color(&mut out, &color, options.format, options.precision)?;
+		writeln!(out, ": {} occurrence(s).", count)?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn count_occurrences_sorts_by_count() {
+		let red = Rgba::new(1.0, 0.0, 0.0, 1.0);
+		let blue = Rgba::new(0.0, 0.0, 1.0, 1.0);
+		let green = Rgba::new(0.0, 1.0, 0.0, 1.0);
+
+		let result = count_occurrences(&[red, blue, green, blue, green, blue]);
+		assert_eq!(result, vec![(blue, 3), (green, 2), (red, 1)]);
+	}
+}
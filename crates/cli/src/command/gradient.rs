@@ -0,0 +1,57 @@
+use std::io::Write;
+
+use palette::rgb::Rgba;
+use palette::WithAlpha;
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::gradient::{linear_gradient_linear, linear_gradient_oklch, linear_gradient_srgb};
+
+use crate::color_printing::print_color;
+use crate::options::{value_name, GradientSpace, Options};
+
+/// Creates the gradient colors, interpolating the alpha channel separately in the same steps.
+fn gradient(start_color: &Rgba, end_color: &Rgba, steps: usize, space: GradientSpace) -> Vec<Rgba> {
+	let start = start_color.without_alpha();
+	let end = end_color.without_alpha();
+	let colors = match space {
+		GradientSpace::Srgb => linear_gradient_srgb(&start, &end, steps),
+		GradientSpace::Linear => linear_gradient_linear(&start, &end, steps),
+		GradientSpace::Oklch => linear_gradient_oklch(&start, &end, steps),
+	};
+
+	colors
+		.into_iter()
+		.enumerate()
+		.map(|(i, color)| {
+			let t = if steps > 1 {
+				i as f32 / (steps - 1) as f32
+			} else {
+				0.0
+			};
+			color.with_alpha(start_color.alpha + (end_color.alpha - start_color.alpha) * t)
+		})
+		.collect()
+}
+
+pub fn print_gradient(
+	start_color: &Rgba,
+	end_color: &Rgba,
+	steps: u32,
+	space: GradientSpace,
+	options: &Options,
+) -> std::io::Result<()> {
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+
+	write!(out, "Gradient from ")?;
+	print_color(&mut out, start_color, options.format, options.precision)?;
+	write!(out, " to ")?;
+	print_color(&mut out, end_color, options.format, options.precision)?;
+	writeln!(out, " in {}:", value_name(&space))?;
+	writeln!(out, "-------")?;
+
+	for color in gradient(start_color, end_color, steps as usize, space) {
+		print_color(&mut out, &color, options.format, options.precision)?;
+		writeln!(out)?;
+	}
+	Ok(())
+}
@@ -0,0 +1,28 @@
+use std::io::Write;
+
+use palette::rgb::Rgba;
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::operations::mix_in_oklab;
+
+use crate::color_printing::print_color;
+use crate::options::Options;
+
+pub fn print_mix(
+	color_1: &Rgba,
+	color_2: &Rgba,
+	ratio: f32,
+	options: &Options,
+) -> std::io::Result<()> {
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+
+	let mixed = mix_in_oklab(color_1, color_2, ratio);
+
+	write!(out, "Mix of ")?;
+	print_color(&mut out, color_1, options.format, options.precision)?;
+	write!(out, " and ")?;
+	print_color(&mut out, color_2, options.format, options.precision)?;
+	write!(out, " at ratio {} is ", ratio)?;
+	print_color(&mut out, &mixed, options.format, options.precision)?;
+	writeln!(out, ".")
+}
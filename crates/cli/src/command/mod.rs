@@ -1,5 +1,33 @@
-pub use contrast::print_contrast;
-pub use details::print_details;
+pub use audit::print_audit;
+pub use blend::print_blend;
+pub use complement::print_complement;
+pub use contrast::{print_contrast, print_contrast_file};
+pub use convert::print_convert;
+pub use details::{print_details, print_details_multiple};
+pub use diff::print_diff;
+pub use extract::print_extract;
+pub use gradient::print_gradient;
+pub use mix::print_mix;
+pub use palette::print_palette;
+pub use scheme::print_scheme;
+pub use simulate::print_simulation;
+pub use sort::print_sort;
+pub use suggest::print_suggestion;
+pub use validate::print_validate;
 
+pub mod audit;
+pub mod blend;
+pub mod complement;
 pub mod contrast;
+pub mod convert;
 pub mod details;
+pub mod diff;
+pub mod extract;
+pub mod gradient;
+pub mod mix;
+pub mod palette;
+pub mod scheme;
+pub mod simulate;
+pub mod sort;
+pub mod suggest;
+pub mod validate;
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use palette::rgb::Rgba;
+use palette::{Hsla, IntoColor};
+use termcolor::{ColorChoice, StandardStream};
+
+use crate::color_printing::{format_color, print_color};
+use crate::options::{ColorFormat, Options, PaletteOutput};
+use crate::output::print_css_vars;
+
+/// Generates `steps` colors with the hue and saturation of `color`, from dark to light.
+/// Lightness values are evenly spaced, excluding black and white.
+fn monochromatic_palette(color: &Rgba, steps: u32) -> Vec<Rgba> {
+	let hsla: Hsla = (*color).into_color();
+	(1..=steps)
+		.map(|i| {
+			let lightness = i as f32 / (steps + 1) as f32;
+			Hsla::new(hsla.hue, hsla.saturation, lightness, hsla.alpha).into_color()
+		})
+		.collect()
+}
+
+/// Creates custom properties for the colors, numbered in steps of 100 like in Tailwind,
+/// where lower numbers are lighter, e.g. `color-100`.
+fn css_vars(
+	colors: &[Rgba],
+	name: &str,
+	format: ColorFormat,
+	precision: u8,
+) -> HashMap<String, String> {
+	colors
+		.iter()
+		.rev()
+		.enumerate()
+		.map(|(i, palette_color)| {
+			(
+				format!("{}-{}", name, (i + 1) * 100),
+				format_color(palette_color, format, precision),
+			)
+		})
+		.collect()
+}
+
+pub fn print_palette(
+	color: &Rgba,
+	steps: u32,
+	output: PaletteOutput,
+	css_var_name: &str,
+	options: &Options,
+) -> std::io::Result<()> {
+	let colors = monochromatic_palette(color, steps);
+
+	match output {
+		PaletteOutput::Terminal => {
+			let mut out = StandardStream::stdout(ColorChoice::Auto);
+			write!(out, "Palette for color ")?;
+			print_color(&mut out, color, options.format, options.precision)?;
+			writeln!(out, ":")?;
+			writeln!(out, "-------")?;
+
+			for palette_color in &colors {
+				print_color(&mut out, palette_color, options.format, options.precision)?;
+				writeln!(out)?;
+			}
+		}
+		PaletteOutput::Css => print_css_vars(&css_vars(
+			&colors,
+			css_var_name,
+			options.format,
+			options.precision,
+		))?,
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use palette::Hsl;
+
+	use super::*;
+
+	#[test]
+	fn monochromatic_palette_spaces_lightness() {
+		let color = Rgba::new(1.0, 0.0, 0.0, 1.0);
+
+		let lightness_values = monochromatic_palette(&color, 3)
+			.iter()
+			.map(|palette_color| {
+				let hsl: Hsl = palette_color.color.into_color();
+				(hsl.lightness * 100.0).round()
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(lightness_values, vec![25.0, 50.0, 75.0]);
+	}
+}
@@ -0,0 +1,43 @@
+use std::io::Write;
+
+use palette::rgb::Rgba;
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::operations::rotate_hue;
+
+use crate::color_printing::print_color;
+use crate::options::{Options, SchemeType};
+
+/// Hue rotations in degrees of the colors in the scheme, starting with the initial color.
+fn hue_offsets(scheme_type: SchemeType) -> &'static [f32] {
+	match scheme_type {
+		SchemeType::Analogous => &[0.0, -30.0, 30.0],
+		SchemeType::Triadic => &[0.0, 120.0, 240.0],
+		SchemeType::Tetradic => &[0.0, 90.0, 180.0, 270.0],
+		SchemeType::SplitComplementary => &[0.0, 150.0, 210.0],
+	}
+}
+
+pub fn print_scheme(
+	color: &Rgba,
+	scheme_type: SchemeType,
+	options: &Options,
+) -> std::io::Result<()> {
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+
+	write!(out, "Scheme for color ")?;
+	print_color(&mut out, color, options.format, options.precision)?;
+	writeln!(out, ":")?;
+	writeln!(out, "-------")?;
+
+	for offset in hue_offsets(scheme_type) {
+		print_color(
+			&mut out,
+			&rotate_hue(color, *offset),
+			options.format,
+			options.precision,
+		)?;
+		writeln!(out)?;
+	}
+	Ok(())
+}
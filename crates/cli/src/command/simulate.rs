@@ -0,0 +1,27 @@
+use std::io::Write;
+
+use palette::rgb::Rgba;
+use palette::WithAlpha;
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::simulation::simulate_color_blindness;
+
+use crate::color_printing::print_color;
+use crate::options::{value_name, Options, SimulationMode};
+
+pub fn print_simulation(
+	color: &Rgba,
+	mode: SimulationMode,
+	options: &Options,
+) -> std::io::Result<()> {
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+
+	let simulated =
+		simulate_color_blindness(&color.without_alpha(), mode.into()).with_alpha(color.alpha);
+
+	write!(out, "Color ")?;
+	print_color(&mut out, color, options.format, options.precision)?;
+	write!(out, " with {} is perceived as ", value_name(&mode))?;
+	print_color(&mut out, &simulated, options.format, options.precision)?;
+	writeln!(out, ".")
+}
@@ -0,0 +1,106 @@
+use std::io::{BufRead, Error, ErrorKind, Write};
+
+use palette::rgb::Rgba;
+use palette::{FromColor, Hsl, Oklch};
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::contrast::relative_luminance;
+use color_utils::parser::parse_color_unclamped;
+
+use crate::color_parser::clamp_to_srgb_gamut;
+use crate::color_printing::print_color;
+use crate::options::{Options, SortKey};
+
+fn sort_value(color: &Rgba, sort_key: SortKey) -> f32 {
+	match sort_key {
+		SortKey::Luminance => relative_luminance(color),
+		SortKey::Hue => Hsl::from_color(color.color).hue.into_positive_degrees(),
+		SortKey::Saturation => Hsl::from_color(color.color).saturation,
+		SortKey::Chroma => Oklch::from_color(color.color).chroma,
+	}
+}
+
+/// Sorts the colors ascending by the given key, or descending if `reverse` is set.
+/// Colors with equal values keep their order.
+fn sort_colors(colors: &mut [Rgba], sort_key: SortKey, reverse: bool) {
+	colors.sort_by(|color_1, color_2| {
+		let ordering = sort_value(color_1, sort_key).total_cmp(&sort_value(color_2, sort_key));
+		if reverse {
+			ordering.reverse()
+		} else {
+			ordering
+		}
+	});
+}
+
+/// Reads one color per line from stdin, skipping empty lines.
+/// Colors outside of the sRGB gamut are clamped, see [`clamp_to_srgb_gamut`].
+fn read_colors_from_stdin(warn_gamut: bool, options: &Options) -> std::io::Result<Vec<Rgba>> {
+	let mut colors = Vec::new();
+	for (i, line) in std::io::stdin().lock().lines().enumerate() {
+		let line = line?;
+		let line = line.trim();
+		if line.is_empty() {
+			continue;
+		}
+		let mut color = parse_color_unclamped(line).map_err(|err| {
+			Error::new(ErrorKind::InvalidData, format!("Line {}: {}", i + 1, err))
+		})?;
+		clamp_to_srgb_gamut(&mut color, warn_gamut, options);
+		colors.push(color);
+	}
+	Ok(colors)
+}
+
+pub fn print_sort(
+	colors: &[Rgba],
+	sort_key: SortKey,
+	reverse: bool,
+	stdin: bool,
+	warn_gamut: bool,
+	options: &Options,
+) -> std::io::Result<()> {
+	let mut colors = colors.to_vec();
+	if stdin {
+		colors.extend(read_colors_from_stdin(warn_gamut, options)?);
+	}
+	sort_colors(&mut colors, sort_key, reverse);
+
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+	for color in &colors {
+		print_color(&mut out, color, options.format, options.precision)?;
+		writeln!(out)?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sort_colors_is_stable() {
+		let black = Rgba::new(0.0, 0.0, 0.0, 1.0);
+		let transparent_black = Rgba::new(0.0, 0.0, 0.0, 0.0);
+		let white = Rgba::new(1.0, 1.0, 1.0, 1.0);
+		let mut colors = [white, black, transparent_black];
+
+		sort_colors(&mut colors, SortKey::Luminance, false);
+		assert_eq!(colors, [black, transparent_black, white]);
+
+		sort_colors(&mut colors, SortKey::Luminance, true);
+		assert_eq!(colors, [white, black, transparent_black]);
+	}
+
+	#[test]
+	fn sort_colors_by_hue() {
+		let red = Rgba::new(1.0, 0.0, 0.0, 1.0);
+		let green = Rgba::new(0.0, 1.0, 0.0, 1.0);
+		let blue = Rgba::new(0.0, 0.0, 1.0, 1.0);
+		let mut colors = [blue, red, green];
+
+		sort_colors(&mut colors, SortKey::Hue, false);
+
+		assert_eq!(colors, [red, green, blue]);
+	}
+}
@@ -0,0 +1,44 @@
+use std::io::Write;
+
+use palette::rgb::Rgba;
+use palette::WithAlpha;
+use termcolor::{ColorChoice, StandardStream};
+
+use color_utils::contrast::{nearest_color_for_level, ContrastLevel};
+
+use crate::color_printing::print_color;
+use crate::options::Options;
+
+pub fn print_suggestion(
+	foreground_color: &Rgba,
+	background_color: &Rgba,
+	level: ContrastLevel,
+	options: &Options,
+) -> std::io::Result<()> {
+	let mut out = StandardStream::stdout(ColorChoice::Auto);
+
+	let suggestion = nearest_color_for_level(
+		&foreground_color.without_alpha(),
+		&background_color.without_alpha(),
+		level,
+	)
+	.with_alpha(foreground_color.alpha);
+
+	write!(out, "Closest color to ")?;
+	print_color(
+		&mut out,
+		foreground_color,
+		options.format,
+		options.precision,
+	)?;
+	write!(out, " reaching {} on background ", level.short_name())?;
+	print_color(
+		&mut out,
+		background_color,
+		options.format,
+		options.precision,
+	)?;
+	write!(out, " is ")?;
+	print_color(&mut out, &suggestion, options.format, options.precision)?;
+	writeln!(out, ".")
+}
@@ -0,0 +1,60 @@
+use palette::WithAlpha;
+
+use color_utils::gamut::is_in_srgb_gamut;
+use color_utils::parser::{parse_color_strict, parse_color_unclamped};
+
+/// Checks if the string is a valid color.
+/// In strict mode, values out of range and colors outside of the sRGB gamut are rejected as well.
+fn validate_color(seq: &str, strict: bool) -> Result<(), String> {
+	let color = parse_color_unclamped(seq).map_err(|err| err.to_string())?;
+	if strict {
+		parse_color_strict(seq).map_err(|err| err.to_string())?;
+		if !is_in_srgb_gamut(&color.without_alpha()) {
+			return Err(String::from("Color is outside of the sRGB gamut."));
+		}
+	}
+	Ok(())
+}
+
+/// Prints if the string is a valid color, the error going to stderr.
+/// Nothing is printed if `quiet` is set.
+/// Returns if the color is valid.
+pub fn print_validate(seq: &str, strict: bool, quiet: bool) -> std::io::Result<bool> {
+	match validate_color(seq, strict) {
+		Ok(()) => {
+			if !quiet {
+				println!("'{}' is a valid color.", seq);
+			}
+			Ok(true)
+		}
+		Err(err) => {
+			if !quiet {
+				eprintln!("'{}' is not a valid color: {}", seq, err);
+			}
+			Ok(false)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn validate_color_accepts_valid() {
+		assert!(validate_color("#FF0000", false).is_ok());
+		assert!(validate_color("rgb(255 0 0)", true).is_ok());
+	}
+
+	#[test]
+	fn validate_color_rejects_invalid() {
+		assert!(validate_color("not-a-color", false).is_err());
+	}
+
+	#[test]
+	fn validate_color_strict_rejects_out_of_gamut() {
+		assert!(validate_color("lab(60 150 0)", false).is_ok());
+		assert!(validate_color("lab(60 150 0)", true).is_err());
+		assert!(validate_color("rgb(300 0 0)", true).is_err());
+	}
+}
@@ -0,0 +1,10 @@
+use std::io::Write;
+
+use serde::Serialize;
+
+/// Prints the value as pretty JSON to stdout, without any terminal colors.
+pub fn print_json<T: Serialize>(value: &T) -> std::io::Result<()> {
+	let mut out = std::io::stdout().lock();
+	serde_json::to_writer_pretty(&mut out, value)?;
+	writeln!(out)
+}
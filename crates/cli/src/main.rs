@@ -1,14 +1,32 @@
-use clap::{Parser, Subcommand};
-use color_parser::parse_color;
-use options::{ColorFormat, Options};
+use std::path::PathBuf;
+
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
+use color_parser::{clamp_to_srgb_gamut, parse_color_arg};
+use color_utils::to_str::{FULL_DECIMAL_PRECISION, MAX_DECIMAL_PRECISION};
+use options::{
+	BlendModeArg, ColorFormat, ContrastLevelArg, ContrastType, DiffMetric, GradientSpace, Options,
+	PaletteOutput, SchemeType, SimulationMode, SortKey,
+};
 use palette::Srgba;
 
 mod color_parser;
 mod color_printing;
 mod command;
+mod json_printing;
 mod options;
+mod output;
+
+/// Exit code if the checked colors do not reach the required contrast level.
+/// Distinct from errors, e.g. if a file cannot be read, which exit with code 1,
+/// and from invalid arguments, which exit with code 2.
+const EXIT_CODE_LEVEL_NOT_REACHED: i32 = 3;
 
-const COLOR_ARG_HELP: &str = "CSS-like color value, e.g. '#00FF11' or 'rgb(255 128 0)'";
+/// Maximum number of palette colors for the CSS output, so that the numbering stays within 100 to 900.
+const MAX_CSS_PALETTE_STEPS: u32 = 9;
+
+const COLOR_ARG_HELP: &str =
+	"CSS-like color value, e.g. '#00FF11' or 'rgb(255 128 0)', or '-' to read the next line of stdin";
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
@@ -16,6 +34,7 @@ const COLOR_ARG_HELP: &str = "CSS-like color value, e.g. '#00FF11' or 'rgb(255 1
 struct Cli {
 	#[arg(
 		long,
+		global = true,
 		required = false,
 		default_value = "auto",
 		value_enum,
@@ -23,6 +42,31 @@ struct Cli {
 	)]
 	format: ColorFormat,
 
+	#[arg(
+		long,
+		global = true,
+		help = "Print the results of the details and contrast commands as JSON"
+	)]
+	json: bool,
+
+	#[arg(
+		long,
+		global = true,
+		default_value = "2",
+		allow_negative_numbers = true,
+		value_parser = parse_precision,
+		help = "How many decimal places to print for colors, contrasts and differences, from 0 to 6, or -1 for full precision"
+	)]
+	precision: u8,
+
+	#[arg(
+		long,
+		global = true,
+		alias = "p3-gamut-warning",
+		help = "Print a warning if a color is outside of the sRGB gamut, e.g. from 'lab()' or 'color(display-p3 ...)', and is clamped"
+	)]
+	warn_gamut: bool,
+
 	#[command(subcommand)]
 	command: Commands,
 }
@@ -31,31 +75,548 @@ struct Cli {
 enum Commands {
 	#[command(about = "Prints the details of a color")]
 	Details {
-		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color)]
-		color: Srgba,
+		#[arg(
+			required_unless_present = "multiple",
+			help = COLOR_ARG_HELP,
+			value_parser = parse_color_arg
+		)]
+		color: Option<Srgba>,
+
+		#[arg(
+			long,
+			conflicts_with_all = ["color", "css_var_name"],
+			help = "List of colors separated by whitespace or commas, e.g. '#FFF, rgb(255, 0, 0)', to print the details of each"
+		)]
+		multiple: Option<String>,
+
+		#[arg(
+			long,
+			value_parser = parse_css_var_name,
+			help = "Print CSS custom properties with this name instead, e.g. 'primary' for '--primary' and '--primary-hsl', or a JSON object of them with '--json'"
+		)]
+		css_var_name: Option<String>,
 	},
 
 	#[command(about = "Calculates the WCAG contrast of two colors")]
 	Contrast {
-		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color)]
+		#[arg(
+			required_unless_present = "file",
+			help = COLOR_ARG_HELP,
+			value_parser = parse_color_arg
+		)]
+		color: Option<Srgba>,
+
+		#[arg(
+			required_unless_present = "file",
+			help = COLOR_ARG_HELP,
+			value_parser = parse_color_arg
+		)]
+		other_color: Option<Srgba>,
+
+		#[arg(
+			long,
+			conflicts_with_all = ["color", "other_color"],
+			help = "Path to a file with one pair of colors separated by whitespace per line, exits with code 3 if any pair does not reach the minimum level"
+		)]
+		file: Option<PathBuf>,
+
+		#[arg(
+			long,
+			default_value = "aa",
+			value_enum,
+			help = "Which contrast level the pairs in the file have to reach"
+		)]
+		min_level: ContrastLevelArg,
+
+		#[arg(
+			long,
+			conflicts_with = "file",
+			help = "Also print the APCA contrast (WCAG 3.0 draft), using the first color as text and the second as background"
+		)]
+		wcag3: bool,
+
+		#[arg(
+			long,
+			help = "Print contrast levels including their WCAG success criterion, e.g. 'WCAG 2.0 §1.4.3 (AA)'"
+		)]
+		verbose: bool,
+
+		#[arg(
+			long = "type",
+			value_enum,
+			conflicts_with = "file",
+			help = "Only print the contrast levels for this type of content, defaults to all types"
+		)]
+		contrast_type: Option<ContrastType>,
+	},
+
+	#[command(about = "Calculates the perceptual difference (Delta E) of two colors")]
+	Diff {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		color: Srgba,
+
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		other_color: Srgba,
+
+		#[arg(
+			long,
+			default_value = "ciede2000",
+			value_enum,
+			help = "Which metric to use, 'euclidean-rgb' is not perceptual and prints no description"
+		)]
+		metric: DiffMetric,
+	},
+
+	#[command(about = "Converts a color to another format, printing only the result")]
+	Convert {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		color: Srgba,
+
+		#[arg(
+			long,
+			value_enum,
+			help = "Which color format to convert to, defaults to the format option"
+		)]
+		to: Option<ColorFormat>,
+	},
+
+	#[command(about = "Mixes two colors in the Oklab color space")]
+	Mix {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
 		color: Srgba,
 
-		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color)]
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
 		other_color: Srgba,
+
+		#[arg(
+			long,
+			default_value_t = 0.5,
+			value_parser = parse_ratio,
+			help = "Ratio of the second color in the result, from 0.0 to 1.0"
+		)]
+		ratio: f32,
+	},
+
+	#[command(about = "Blends a color onto a base color, e.g. with multiply or screen")]
+	Blend {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		base_color: Srgba,
+
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		blend_color: Srgba,
+
+		#[arg(
+			long,
+			required = true,
+			value_enum,
+			help = "Which blend mode to use, the result keeps the alpha channel of the base color"
+		)]
+		mode: BlendModeArg,
+	},
+
+	#[command(about = "Calculates the complementary color by rotating the hue")]
+	Complement {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		color: Srgba,
+
+		#[arg(
+			long,
+			default_value_t = 180.0,
+			allow_negative_numbers = true,
+			help = "Degrees to rotate the hue by, e.g. 150 or 210 for split-complements"
+		)]
+		angle: f32,
+	},
+
+	#[command(about = "Generates a color scheme by rotating the hue")]
+	Scheme {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		color: Srgba,
+
+		#[arg(
+			long = "type",
+			required = true,
+			value_enum,
+			help = "Which color scheme to generate"
+		)]
+		scheme_type: SchemeType,
+	},
+
+	#[command(about = "Generates a monochromatic palette by varying the HSL lightness")]
+	Palette {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		color: Srgba,
+
+		#[arg(
+			long,
+			default_value_t = 5,
+			value_parser = clap::value_parser!(u32).range(1..),
+			help = "How many colors to generate, from dark to light, at most 9 for the CSS output"
+		)]
+		steps: u32,
+
+		#[arg(
+			long,
+			default_value = "terminal",
+			value_enum,
+			help = "Whether to print a preview or CSS custom properties like '--color-100'"
+		)]
+		output: PaletteOutput,
+
+		#[arg(
+			long,
+			default_value = "color",
+			value_parser = parse_css_var_name,
+			help = "Name of the CSS custom properties when using the CSS output"
+		)]
+		css_var_name: String,
+	},
+
+	#[command(about = "Generates evenly spaced gradient stops between two colors")]
+	Gradient {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		start_color: Srgba,
+
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		end_color: Srgba,
+
+		#[arg(
+			long,
+			default_value_t = 5,
+			value_parser = clap::value_parser!(u32).range(2..),
+			help = "How many colors to generate, including the start and end color"
+		)]
+		steps: u32,
+
+		#[arg(
+			long,
+			default_value = "oklch",
+			value_enum,
+			help = "Which color space to interpolate in, 'oklch' avoids gray or dark middle colors"
+		)]
+		space: GradientSpace,
+	},
+
+	#[command(about = "Simulates how a color is perceived with color vision deficiency")]
+	Simulate {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		color: Srgba,
+
+		#[arg(
+			long,
+			required = true,
+			value_enum,
+			help = "Which color vision deficiency to simulate"
+		)]
+		mode: SimulationMode,
+	},
+
+	#[command(about = "Suggests the closest foreground color reaching a WCAG contrast level")]
+	Suggest {
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		foreground_color: Srgba,
+
+		#[arg(required = true, help = COLOR_ARG_HELP, value_parser = parse_color_arg)]
+		background_color: Srgba,
+
+		#[arg(
+			long,
+			default_value = "aa",
+			value_enum,
+			help = "Which contrast level to reach"
+		)]
+		level: ContrastLevelArg,
+	},
+
+	#[command(about = "Sorts colors, e.g. by luminance or hue")]
+	Sort {
+		#[arg(
+			required_unless_present = "stdin",
+			help = COLOR_ARG_HELP,
+			value_parser = parse_color_arg
+		)]
+		colors: Vec<Srgba>,
+
+		#[arg(long, required = true, value_enum, help = "Which value to sort by")]
+		by: SortKey,
+
+		#[arg(long, help = "Sort in descending order")]
+		reverse: bool,
+
+		#[arg(long, help = "Also read colors from stdin, one per line")]
+		stdin: bool,
+	},
+
+	#[command(about = "Checks if a value is a valid color, exiting with an error if it is not")]
+	Validate {
+		#[arg(
+			required = true,
+			help = "CSS-like color value, e.g. '#00FF11' or 'rgb(255 128 0)'"
+		)]
+		color: String,
+
+		#[arg(
+			long,
+			help = "Also reject values out of range and colors outside of the sRGB gamut"
+		)]
+		strict: bool,
+
+		#[arg(long, help = "Do not print anything, only set the exit code")]
+		quiet: bool,
+	},
+
+	#[command(about = "Lists all colors used in a CSS file")]
+	Extract {
+		#[arg(long, required = true, help = "Path to the CSS file")]
+		file: PathBuf,
+	},
+
+	#[command(
+		about = "Checks the contrast of text and background colors declared in the same rule of a CSS file"
+	)]
+	Audit {
+		#[arg(required = true, help = "Path to the CSS file")]
+		file: PathBuf,
+
+		#[arg(
+			long,
+			default_value = "aa",
+			value_enum,
+			help = "Which contrast level each pair must reach, exits with code 3 if any pair does not"
+		)]
+		level: ContrastLevelArg,
 	},
 }
 
+impl Commands {
+	/// Gets all colors given as arguments.
+	fn colors_mut(&mut self) -> Vec<&mut Srgba> {
+		match self {
+			Commands::Details { color, .. } => color.iter_mut().collect(),
+			Commands::Convert { color, .. }
+			| Commands::Complement { color, .. }
+			| Commands::Scheme { color, .. }
+			| Commands::Palette { color, .. }
+			| Commands::Simulate { color, .. } => vec![color],
+			Commands::Contrast {
+				color, other_color, ..
+			} => color.iter_mut().chain(other_color.iter_mut()).collect(),
+			Commands::Mix {
+				color, other_color, ..
+			}
+			| Commands::Diff {
+				color, other_color, ..
+			} => vec![color, other_color],
+			Commands::Blend {
+				base_color,
+				blend_color,
+				..
+			} => vec![base_color, blend_color],
+			Commands::Gradient {
+				start_color,
+				end_color,
+				..
+			} => vec![start_color, end_color],
+			Commands::Suggest {
+				foreground_color,
+				background_color,
+				..
+			} => vec![foreground_color, background_color],
+			Commands::Sort { colors, .. } => colors.iter_mut().collect(),
+			Commands::Validate { .. } | Commands::Extract { .. } | Commands::Audit { .. } => {
+				Vec::new()
+			}
+		}
+	}
+}
+
+fn parse_ratio(s: &str) -> Result<f32, String> {
+	let ratio: f32 = s.parse().map_err(|_| format!("'{s}' is not a number"))?;
+	if (0.0..=1.0).contains(&ratio) {
+		Ok(ratio)
+	} else {
+		Err(format!("'{s}' is not in the range 0.0 to 1.0"))
+	}
+}
+
+fn parse_precision(s: &str) -> Result<u8, String> {
+	if s == "-1" {
+		return Ok(FULL_DECIMAL_PRECISION);
+	}
+	match s.parse::<u8>() {
+		Ok(precision) if precision <= MAX_DECIMAL_PRECISION => Ok(precision),
+		_ => Err(format!(
+			"'{s}' is not a number of decimal places from 0 to {MAX_DECIMAL_PRECISION} or -1"
+		)),
+	}
+}
+
+fn parse_css_var_name(s: &str) -> Result<String, String> {
+	if !s.is_empty()
+		&& s.chars()
+			.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+	{
+		Ok(String::from(s))
+	} else {
+		Err(format!(
+			"'{s}' must only consist of letters, digits, '-' and '_'"
+		))
+	}
+}
+
 fn main() -> Result<(), std::io::Error> {
-	let args = Cli::parse();
+	let mut args = Cli::parse();
+
+	if let Commands::Palette {
+		steps,
+		output: PaletteOutput::Css,
+		..
+	} = args.command
+	{
+		if steps > MAX_CSS_PALETTE_STEPS {
+			Cli::command()
+				.error(
+					ErrorKind::ValueValidation,
+					format!(
+						"At most {} '--steps' are supported for '--output css'",
+						MAX_CSS_PALETTE_STEPS
+					),
+				)
+				.exit();
+		}
+	}
 
 	let options = Options {
 		format: args.format,
+		json: args.json,
+		precision: args.precision,
 	};
 
+	for color in args.command.colors_mut() {
+		clamp_to_srgb_gamut(color, args.warn_gamut, &options);
+	}
+
 	match args.command {
-		Commands::Details { color } => command::print_details(&color, &options),
-		Commands::Contrast { color, other_color } => {
-			command::print_contrast(&color, &other_color, &options)
+		Commands::Details {
+			color,
+			multiple,
+			css_var_name,
+		} => match (multiple, color) {
+			(Some(multiple), _) => {
+				command::print_details_multiple(&multiple, args.warn_gamut, &options)
+			}
+			(None, Some(color)) => {
+				command::print_details(&color, css_var_name.as_deref(), &options)
+			}
+			_ => unreachable!("Color is required unless multiple colors are given."),
+		},
+		Commands::Contrast {
+			color,
+			other_color,
+			wcag3,
+			verbose,
+			contrast_type,
+			file,
+			min_level,
+		} => match (file, color, other_color) {
+			(Some(file), _, _) => {
+				let all_reached = command::print_contrast_file(
+					&file,
+					min_level.into(),
+					verbose,
+					args.warn_gamut,
+					&options,
+				)?;
+				if !all_reached {
+					std::process::exit(EXIT_CODE_LEVEL_NOT_REACHED);
+				}
+				Ok(())
+			}
+			(None, Some(color), Some(other_color)) => command::print_contrast(
+				&color,
+				&other_color,
+				wcag3,
+				verbose,
+				contrast_type,
+				&options,
+			),
+			_ => unreachable!("Colors are required unless a file is given."),
+		},
+		Commands::Diff {
+			color,
+			other_color,
+			metric,
+		} => command::print_diff(&color, &other_color, metric, &options),
+		Commands::Convert { color, to } => command::print_convert(&color, to, &options),
+		Commands::Mix {
+			color,
+			other_color,
+			ratio,
+		} => command::print_mix(&color, &other_color, ratio, &options),
+		Commands::Blend {
+			base_color,
+			blend_color,
+			mode,
+		} => command::print_blend(&base_color, &blend_color, mode, &options),
+		Commands::Complement { color, angle } => command::print_complement(&color, angle, &options),
+		Commands::Scheme { color, scheme_type } => {
+			command::print_scheme(&color, scheme_type, &options)
 		}
+		Commands::Palette {
+			color,
+			steps,
+			output,
+			css_var_name,
+		} => command::print_palette(&color, steps, output, &css_var_name, &options),
+		Commands::Gradient {
+			start_color,
+			end_color,
+			steps,
+			space,
+		} => command::print_gradient(&start_color, &end_color, steps, space, &options),
+		Commands::Simulate { color, mode } => command::print_simulation(&color, mode, &options),
+		Commands::Suggest {
+			foreground_color,
+			background_color,
+			level,
+		} => command::print_suggestion(&foreground_color, &background_color, level.into(), &options),
+		Commands::Sort {
+			colors,
+			by,
+			reverse,
+			stdin,
+		} => command::print_sort(&colors, by, reverse, stdin, args.warn_gamut, &options),
+		Commands::Validate {
+			color,
+			strict,
+			quiet,
+		} => {
+			if !command::print_validate(&color, strict, quiet)? {
+				std::process::exit(1);
+			}
+			Ok(())
+		}
+		Commands::Extract { file } => command::print_extract(&file, &options),
+		Commands::Audit { file, level } => {
+			if !command::print_audit(&file, level.into(), &options)? {
+				std::process::exit(EXIT_CODE_LEVEL_NOT_REACHED);
+			}
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_precision_limits_range() {
+		assert_eq!(parse_precision("0"), Ok(0));
+		assert_eq!(parse_precision("6"), Ok(MAX_DECIMAL_PRECISION));
+		assert_eq!(parse_precision("-1"), Ok(FULL_DECIMAL_PRECISION));
+		assert!(parse_precision("7").is_err());
+		assert!(parse_precision("40").is_err());
+		assert!(parse_precision("-2").is_err());
 	}
 }
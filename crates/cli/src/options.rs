@@ -1,13 +1,177 @@
+use color_utils::blend::BlendMode;
+use color_utils::contrast::ContrastLevel;
+use color_utils::simulation::ColorBlindnessMode;
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub struct Options {
 	pub format: ColorFormat,
+	pub json: bool,
+	/// Maximum decimal places when formatting colors and numbers, see [`color_utils::to_str::FULL_DECIMAL_PRECISION`].
+	pub precision: u8,
+}
+
+/// Gets the name of the value as accepted on the command line, e.g. `hard-light`.
+pub fn value_name(value: &impl clap::ValueEnum) -> String {
+	value
+		.to_possible_value()
+		.map_or_else(String::new, |possible_value| {
+			String::from(possible_value.get_name())
+		})
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
 pub enum ColorFormat {
 	Auto,
+	#[value(alias = "hex")]
 	RgbHex,
+	#[value(alias = "rgb")]
 	RgbFunction,
+	#[value(alias = "rgb-legacy")]
+	RgbLegacyFunction,
+	#[value(alias = "hsl")]
 	HslFunction,
+	#[value(alias = "hwb")]
 	HwbFunction,
+	#[value(alias = "lab")]
+	LabFunction,
+	#[value(alias = "oklab")]
+	OklabFunction,
+	#[value(alias = "lch")]
+	LchFunction,
+	#[value(alias = "oklch")]
+	OklchFunction,
+	#[value(alias = "p3")]
+	DisplayP3Function,
+	/// Name of the closest CSS named color, ignoring alpha.
+	Named,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
+pub enum SchemeType {
+	Analogous,
+	Triadic,
+	Tetradic,
+	SplitComplementary,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
+pub enum PaletteOutput {
+	Terminal,
+	Css,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
+pub enum GradientSpace {
+	Srgb,
+	Linear,
+	Oklch,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
+pub enum DiffMetric {
+	Cie76,
+	Cie94,
+	Ciede2000,
+	EuclideanRgb,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
+pub enum SortKey {
+	Luminance,
+	Hue,
+	Saturation,
+	Chroma,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
+pub enum SimulationMode {
+	Protanopia,
+	Deuteranopia,
+	Tritanopia,
+	Protanomaly,
+	Deuteranomaly,
+	Tritanomaly,
+}
+
+impl From<SimulationMode> for ColorBlindnessMode {
+	fn from(mode: SimulationMode) -> Self {
+		match mode {
+			SimulationMode::Protanopia => ColorBlindnessMode::Protanopia,
+			SimulationMode::Deuteranopia => ColorBlindnessMode::Deuteranopia,
+			SimulationMode::Tritanopia => ColorBlindnessMode::Tritanopia,
+			SimulationMode::Protanomaly => ColorBlindnessMode::Protanomaly,
+			SimulationMode::Deuteranomaly => ColorBlindnessMode::Deuteranomaly,
+			SimulationMode::Tritanomaly => ColorBlindnessMode::Tritanomaly,
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
+pub enum BlendModeArg {
+	Multiply,
+	Screen,
+	Overlay,
+	SoftLight,
+	HardLight,
+	Difference,
+	Exclusion,
+	Darken,
+	Lighten,
+}
+
+impl From<BlendModeArg> for BlendMode {
+	fn from(mode: BlendModeArg) -> Self {
+		match mode {
+			BlendModeArg::Multiply => BlendMode::Multiply,
+			BlendModeArg::Screen => BlendMode::Screen,
+			BlendModeArg::Overlay => BlendMode::Overlay,
+			BlendModeArg::SoftLight => BlendMode::SoftLight,
+			BlendModeArg::HardLight => BlendMode::HardLight,
+			BlendModeArg::Difference => BlendMode::Difference,
+			BlendModeArg::Exclusion => BlendMode::Exclusion,
+			BlendModeArg::Darken => BlendMode::Darken,
+			BlendModeArg::Lighten => BlendMode::Lighten,
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
+pub enum ContrastLevelArg {
+	LargeAa,
+	Aa,
+	LargeAaa,
+	Aaa,
+	Ui,
+	Focus,
+}
+
+impl From<ContrastLevelArg> for ContrastLevel {
+	fn from(level: ContrastLevelArg) -> Self {
+		match level {
+			ContrastLevelArg::LargeAa => ContrastLevel::LargeAa,
+			ContrastLevelArg::Aa => ContrastLevel::Aa,
+			ContrastLevelArg::LargeAaa => ContrastLevel::LargeAaa,
+			ContrastLevelArg::Aaa => ContrastLevel::Aaa,
+			ContrastLevelArg::Ui => ContrastLevel::UiComponent,
+			ContrastLevelArg::Focus => ContrastLevel::Focus,
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, clap::ValueEnum)]
+pub enum ContrastType {
+	Text,
+	Ui,
+	Focus,
+}
+
+impl ContrastType {
+	/// Checks if the contrast level applies to this type of content.
+	pub fn includes(&self, level: &ContrastLevel) -> bool {
+		match self {
+			ContrastType::Text => level.is_text(),
+			ContrastType::Ui => *level == ContrastLevel::UiComponent,
+			ContrastType::Focus => *level == ContrastLevel::Focus,
+		}
+	}
 }
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Formats the properties as CSS custom property declarations, one per line,
+/// e.g. `--color-primary: #FF8000;`. Names are given without the leading `--`.
+///
+/// Declarations are ordered by name length first, so that e.g. `color-900` precedes `color-1000`
+/// and a base name precedes its suffixed variants.
+pub fn format_css_vars(properties: &HashMap<String, String>) -> String {
+	let mut sorted_properties = properties.iter().collect::<Vec<_>>();
+	sorted_properties.sort_by_key(|(name, _)| (name.len(), name.as_str()));
+
+	sorted_properties
+		.iter()
+		.map(|(name, value)| format!("--{}: {};\n", name, value))
+		.collect()
+}
+
+/// Prints the properties as CSS custom property declarations to stdout, see [`format_css_vars`].
+pub fn print_css_vars(properties: &HashMap<String, String>) -> std::io::Result<()> {
+	let mut out = std::io::stdout().lock();
+	write!(out, "{}", format_css_vars(properties))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn format_css_vars_formats_declarations() {
+		let properties = HashMap::from([
+			(String::from("primary-hsl"), String::from("hsl(30 100 50)")),
+			(String::from("primary"), String::from("#FF8000")),
+		]);
+
+		assert_eq!(
+			format_css_vars(&properties),
+			"--primary: #FF8000;\n--primary-hsl: hsl(30 100 50);\n"
+		);
+	}
+
+	#[test]
+	fn format_css_vars_orders_by_length() {
+		let properties = HashMap::from([
+			(String::from("color-1000"), String::from("#000")),
+			(String::from("color-900"), String::from("#111")),
+			(String::from("color-100"), String::from("#EEE")),
+		]);
+
+		assert_eq!(
+			format_css_vars(&properties),
+			"--color-100: #EEE;\n--color-900: #111;\n--color-1000: #000;\n"
+		);
+	}
+}
@@ -0,0 +1,3 @@
+pub use css_vars::print_css_vars;
+
+pub mod css_vars;
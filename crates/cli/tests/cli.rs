@@ -0,0 +1,128 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Runs the binary with the given arguments, returning stdout.
+fn run(args: &[&str]) -> String {
+	let output = Command::new(env!("CARGO_BIN_EXE_colu"))
+		.args(args)
+		// Disables terminal colors.
+		.env("NO_COLOR", "1")
+		.output()
+		.unwrap();
+	assert!(output.status.success(), "{:?}", output);
+	String::from_utf8(output.stdout).unwrap()
+}
+
+/// Writes the content to a file in the temp directory, unique to this test run so that concurrent runs do not race.
+fn write_temp_file(name: &str, content: &str) -> PathBuf {
+	let file = std::env::temp_dir().join(format!("colu_{}_{}", std::process::id(), name));
+	std::fs::write(&file, content).unwrap();
+	file
+}
+
+#[test]
+fn details_prints_formats() {
+	let stdout = run(&["details", "#FF0000"]);
+
+	assert!(stdout.contains("Formats:"));
+	assert!(stdout.contains("#F00"));
+	assert!(stdout.contains("rgb(255 0 0)"));
+}
+
+#[test]
+fn contrast_prints_levels_reached() {
+	let stdout = run(&["contrast", "#000000", "#FFFFFF"]);
+
+	assert!(stdout.contains("21.00"));
+	assert!(stdout.contains("AAA"));
+}
+
+#[test]
+fn contrast_rejects_invalid_color() {
+	let output = Command::new(env!("CARGO_BIN_EXE_colu"))
+		.args(["contrast", "#000000", "not-a-color"])
+		.output()
+		.unwrap();
+
+	assert!(!output.status.success());
+}
+
+#[test]
+fn contrast_file_exits_if_level_not_reached() {
+	let file = write_temp_file("contrast_file.txt", "#000 #FFF\n#777 #888\n");
+
+	let output = Command::new(env!("CARGO_BIN_EXE_colu"))
+		.args(["contrast", "--file", file.to_str().unwrap()])
+		.env("NO_COLOR", "1")
+		.output()
+		.unwrap();
+	std::fs::remove_file(&file).unwrap();
+
+	assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn contrast_file_rejects_wcag3() {
+	let output = Command::new(env!("CARGO_BIN_EXE_colu"))
+		.args(["contrast", "--file", "colors.txt", "--wcag3"])
+		.output()
+		.unwrap();
+
+	assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn contrast_file_rejects_type() {
+	let output = Command::new(env!("CARGO_BIN_EXE_colu"))
+		.args(["contrast", "--file", "colors.txt", "--type", "text"])
+		.output()
+		.unwrap();
+
+	assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn audit_composites_translucent_text() {
+	let file = write_temp_file(
+		"audit.css",
+		"p { color: rgba(0, 0, 0, 0.3); background-color: #FFF; }\n",
+	);
+
+	let output = Command::new(env!("CARGO_BIN_EXE_colu"))
+		.args(["audit", file.to_str().unwrap()])
+		.env("NO_COLOR", "1")
+		.output()
+		.unwrap();
+	std::fs::remove_file(&file).unwrap();
+
+	assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn palette_css_rejects_too_many_steps() {
+	let output = Command::new(env!("CARGO_BIN_EXE_colu"))
+		.args(["palette", "#FF0000", "--output", "css", "--steps", "10"])
+		.output()
+		.unwrap();
+
+	assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn validate_accepts_valid_color() {
+	let stdout = run(&["validate", "rgb(255 0 0)"]);
+
+	assert!(stdout.contains("is a valid color"));
+}
+
+#[test]
+fn validate_quiet_rejects_invalid_color() {
+	let output = Command::new(env!("CARGO_BIN_EXE_colu"))
+		.args(["validate", "--quiet", "not-a-color"])
+		.output()
+		.unwrap();
+
+	assert_eq!(output.status.code(), Some(1));
+	assert!(output.stdout.is_empty());
+	assert!(output.stderr.is_empty());
+}
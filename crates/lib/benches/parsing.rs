@@ -0,0 +1,39 @@
+//! Compares the throughput of the regex-free parser to the regex-based and `cssparser`-based ones.
+//! Run with `cargo bench -p color-utils`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use palette::Srgba;
+
+use color_utils::parser::{
+	from_rgb_modern_function_str, parse_color, parse_rgb_fast, ParsingError,
+};
+
+const ITERATIONS: usize = 10_000;
+
+fn measure(parse: impl Fn(&str) -> Result<Srgba, ParsingError>, seq: &str) -> Duration {
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		black_box(parse(black_box(seq)).unwrap());
+	}
+	start.elapsed()
+}
+
+fn compare(name: &str, seq: &str, baseline: impl Fn(&str) -> Result<Srgba, ParsingError>) {
+	let baseline_duration = measure(baseline, seq);
+	let fast_duration = measure(parse_rgb_fast, seq);
+	println!(
+		"{name}: {ITERATIONS} parses of '{seq}' took {baseline_duration:?}, {fast_duration:?} with parse_rgb_fast."
+	);
+}
+
+fn main() {
+	compare("parse_color", "#FF8000", parse_color);
+	compare("parse_color", "rgb(255 128 0 / 0.5)", parse_color);
+	compare(
+		"from_rgb_modern_function_str",
+		"rgb(255 128 0 / 0.5)",
+		from_rgb_modern_function_str,
+	);
+}
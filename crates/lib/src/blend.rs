@@ -0,0 +1,296 @@
+use palette::rgb::Rgb;
+use palette::{LinSrgb, Srgba, WithAlpha};
+
+use crate::gradient::{
+	from_components, hue_index, mix_components, to_component_pair, HueInterpolationMethod,
+	InterpolationColorSpace,
+};
+use crate::util::{from_linear_srgb, to_linear_srgb};
+
+/// Separable blend modes as known from image editors.
+/// For details see the [Compositing and Blending specification](https://www.w3.org/TR/compositing-1/#blending).
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum BlendMode {
+	/// Multiplies the channels, the result is always at least as dark as either color.
+	Multiply,
+
+	/// Inverse of multiplying the inverted channels, the result is always at least as light as either color.
+	Screen,
+
+	/// Multiplies or screens depending on the base color, keeping its highlights and shadows.
+	Overlay,
+
+	/// Darkens or lightens depending on the blend color, like a diffuse spotlight.
+	SoftLight,
+
+	/// Multiplies or screens depending on the blend color, like a harsh spotlight.
+	HardLight,
+
+	/// Absolute difference of the channels.
+	Difference,
+
+	/// Like [`BlendMode::Difference`], but with lower contrast.
+	Exclusion,
+
+	/// Minimum of the channels.
+	Darken,
+
+	/// Maximum of the channels.
+	Lighten,
+}
+
+fn multiply(base: f32, blend: f32) -> f32 {
+	base * blend
+}
+
+fn screen(base: f32, blend: f32) -> f32 {
+	base + blend - base * blend
+}
+
+fn hard_light(base: f32, blend: f32) -> f32 {
+	if blend <= 0.5 {
+		multiply(base, 2.0 * blend)
+	} else {
+		screen(base, 2.0 * blend - 1.0)
+	}
+}
+
+fn soft_light(base: f32, blend: f32) -> f32 {
+	if blend <= 0.5 {
+		base - (1.0 - 2.0 * blend) * base * (1.0 - base)
+	} else {
+		let d = if base <= 0.25 {
+			((16.0 * base - 12.0) * base + 4.0) * base
+		} else {
+			base.sqrt()
+		};
+		base + (2.0 * blend - 1.0) * (d - base)
+	}
+}
+
+fn blend_channel(base: f32, blend: f32, mode: BlendMode) -> f32 {
+	match mode {
+		BlendMode::Multiply => multiply(base, blend),
+		BlendMode::Screen => screen(base, blend),
+		BlendMode::Overlay => hard_light(blend, base),
+		BlendMode::SoftLight => soft_light(base, blend),
+		BlendMode::HardLight => hard_light(base, blend),
+		BlendMode::Difference => (base - blend).abs(),
+		BlendMode::Exclusion => base + blend - 2.0 * base * blend,
+		BlendMode::Darken => base.min(blend),
+		BlendMode::Lighten => base.max(blend),
+	}
+}
+
+/// Blends `blend` onto `base` using the given mode, operating in linear sRGB.
+/// The blend color is treated as opaque, so when working with colors with alpha channel,
+/// the result should keep the alpha channel of `base`.
+pub fn blend(base: &Rgb, blend: &Rgb, mode: BlendMode) -> Rgb {
+	let base_linear = to_linear_srgb(base);
+	let blend_linear = to_linear_srgb(blend);
+	from_linear_srgb(&LinSrgb::new(
+		blend_channel(base_linear.red, blend_linear.red, mode),
+		blend_channel(base_linear.green, blend_linear.green, mode),
+		blend_channel(base_linear.blue, blend_linear.blue, mode),
+	))
+}
+
+/// Color space and hue interpolation method to mix colors with, like `in oklch longer hue` in CSS.
+/// The hue interpolation method only applies to color spaces with a hue, i.e. Oklch and HSL.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct ColorMixMethod {
+	pub space: InterpolationColorSpace,
+	pub hue_interpolation: HueInterpolationMethod,
+}
+
+/// Multiplies the components of the color space by the alpha, except for the hue.
+fn premultiply(components: [f32; 3], alpha: f32, space: InterpolationColorSpace) -> [f32; 3] {
+	let hue_index = hue_index(space);
+	let mut premultiplied = components;
+	for (i, component) in premultiplied.iter_mut().enumerate() {
+		if hue_index != Some(i) {
+			*component *= alpha;
+		}
+	}
+	premultiplied
+}
+
+/// Mixes two colors like CSS `color-mix()`, with the amounts of each color given as fractions, e.g. `0.4` for 40%.
+/// If the amounts do not add up to 1, they are scaled to do so. If they add up to less than 1,
+/// the alpha of the result is reduced by that factor, e.g. mixing two colors at 25% each results in 50% alpha.
+/// To mimic omitted percentages in CSS, pass `1 - amount` for the other color, or `0.5` for both if neither is given.
+///
+/// Colors are interpolated with premultiplied alpha. If one of the colors has no hue (i.e. is achromatic),
+/// the hue of the other color is used. The result is not clamped to the sRGB gamut,
+/// e.g. mixing in Lab may result in colors outside of it.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-5/#color-mix).
+///
+/// # Panics
+///
+/// Panics if an amount is negative or both are 0.
+pub fn color_mix(
+	method: ColorMixMethod,
+	color_1: &Srgba,
+	amount_1: f32,
+	color_2: &Srgba,
+	amount_2: f32,
+) -> Srgba {
+	assert!(
+		amount_1 >= 0.0 && amount_2 >= 0.0 && amount_1 + amount_2 > 0.0,
+		"Amounts must not be negative and not both be 0."
+	);
+	let sum = amount_1 + amount_2;
+	let alpha_multiplier = sum.min(1.0);
+	let t = amount_2 / sum;
+
+	let (components_1, components_2) = to_component_pair(
+		&color_1.color,
+		&color_2.color,
+		method.space,
+		method.hue_interpolation,
+	);
+	let alpha = color_1.alpha * (1.0 - t) + color_2.alpha * t;
+	let components = if alpha == 0.0 {
+		mix_components(components_1, components_2, t, method.space)
+	} else {
+		let components = mix_components(
+			premultiply(components_1, color_1.alpha, method.space),
+			premultiply(components_2, color_2.alpha, method.space),
+			t,
+			method.space,
+		);
+		premultiply(components, 1.0 / alpha, method.space)
+	};
+
+	from_components(components, method.space).with_alpha(alpha * alpha_multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+	use palette::{FromColor, Hsl};
+
+	use super::*;
+
+	fn assert_rgb_eq(actual: Rgb, expected: Rgb) {
+		let difference = (actual.red - expected.red)
+			.abs()
+			.max((actual.green - expected.green).abs())
+			.max((actual.blue - expected.blue).abs());
+		assert!(difference < 1e-5, "{actual:?} != {expected:?}");
+	}
+
+	#[test]
+	fn blend_multiply_and_screen_with_extremes() {
+		let color = Rgb::new(0.2, 0.5, 0.8);
+		let white = Rgb::new(1.0, 1.0, 1.0);
+		let black = Rgb::new(0.0, 0.0, 0.0);
+
+		assert_rgb_eq(blend(&color, &white, BlendMode::Multiply), color);
+		assert_rgb_eq(blend(&color, &black, BlendMode::Multiply), black);
+		assert_rgb_eq(blend(&color, &black, BlendMode::Screen), color);
+		assert_rgb_eq(blend(&color, &white, BlendMode::Screen), white);
+	}
+
+	#[test]
+	fn blend_difference_of_same_color_is_black() {
+		let color = Rgb::new(0.2, 0.5, 0.8);
+
+		assert_rgb_eq(
+			blend(&color, &color, BlendMode::Difference),
+			Rgb::new(0.0, 0.0, 0.0),
+		);
+	}
+
+	#[test]
+	fn blend_darken_and_lighten() {
+		let base = Rgb::new(0.2, 0.5, 0.8);
+		let other = Rgb::new(0.8, 0.5, 0.2);
+
+		assert_rgb_eq(
+			blend(&base, &other, BlendMode::Darken),
+			Rgb::new(0.2, 0.5, 0.2),
+		);
+		assert_rgb_eq(
+			blend(&base, &other, BlendMode::Lighten),
+			Rgb::new(0.8, 0.5, 0.8),
+		);
+	}
+
+	#[test]
+	fn blend_overlay_swaps_hard_light() {
+		let base = Rgb::new(0.2, 0.5, 0.8);
+		let other = Rgb::new(0.7, 0.1, 0.4);
+
+		assert_rgb_eq(
+			blend(&base, &other, BlendMode::Overlay),
+			blend(&other, &base, BlendMode::HardLight),
+		);
+	}
+
+	const SRGB: ColorMixMethod = ColorMixMethod {
+		space: InterpolationColorSpace::Srgb,
+		hue_interpolation: HueInterpolationMethod::Shorter,
+	};
+
+	#[test]
+	fn color_mix_weights_colors() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let blue = Srgba::new(0.0, 0.0, 1.0, 1.0);
+
+		let result = color_mix(SRGB, &red, 0.75, &blue, 0.25);
+		assert_rgb_eq(result.color, Rgb::new(0.75, 0.0, 0.25));
+		assert_eq!(result.alpha, 1.0);
+	}
+
+	#[test]
+	fn color_mix_scales_amounts() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let blue = Srgba::new(0.0, 0.0, 1.0, 1.0);
+
+		let result = color_mix(SRGB, &red, 1.5, &blue, 0.5);
+		assert_rgb_eq(result.color, Rgb::new(0.75, 0.0, 0.25));
+		assert_eq!(result.alpha, 1.0);
+
+		let result = color_mix(SRGB, &red, 0.25, &blue, 0.25);
+		assert_rgb_eq(result.color, Rgb::new(0.5, 0.0, 0.5));
+		assert_eq!(result.alpha, 0.5);
+	}
+
+	#[test]
+	fn color_mix_premultiplies_alpha() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let transparent = Srgba::new(0.0, 0.0, 1.0, 0.0);
+
+		let result = color_mix(SRGB, &red, 0.5, &transparent, 0.5);
+		assert_rgb_eq(result.color, Rgb::new(1.0, 0.0, 0.0));
+		assert_eq!(result.alpha, 0.5);
+	}
+
+	#[test]
+	fn color_mix_interpolates_hue() {
+		// Hues of 0 and 120 degrees.
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let green = Srgba::new(0.0, 1.0, 0.0, 1.0);
+		let hue_of_mix = |hue_interpolation| {
+			let method = ColorMixMethod {
+				space: InterpolationColorSpace::Hsl,
+				hue_interpolation,
+			};
+			let result = color_mix(method, &red, 0.5, &green, 0.5);
+			Hsl::from_color(result.color).hue.into_positive_degrees()
+		};
+
+		assert!((hue_of_mix(HueInterpolationMethod::Shorter) - 60.0).abs() < 0.1);
+		assert!((hue_of_mix(HueInterpolationMethod::Longer) - 240.0).abs() < 0.1);
+		assert!((hue_of_mix(HueInterpolationMethod::Increasing) - 60.0).abs() < 0.1);
+		assert!((hue_of_mix(HueInterpolationMethod::Decreasing) - 240.0).abs() < 0.1);
+	}
+
+	#[test]
+	#[should_panic]
+	fn color_mix_rejects_zero_amounts() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+
+		color_mix(SRGB, &red, 0.0, &red, 0.0);
+	}
+}
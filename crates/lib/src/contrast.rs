@@ -0,0 +1,778 @@
+use core::fmt;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use palette::blend::Compose;
+use palette::color_difference::Wcag21RelativeContrast;
+use palette::rgb::Rgb;
+use palette::{FromColor, IntoColor, Oklab, Srgba, WithAlpha};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::math::floor_n_decimals;
+use crate::to_str::MAX_DECIMAL_PRECISION;
+use crate::util::{BLACK, WHITE};
+
+/// Contrast target values based on
+/// <https://www.w3.org/TR/2008/REC-WCAG20-20081211/#visual-audio-contrast-contrast>,
+/// as well as <https://www.w3.org/TR/WCAG22/#non-text-contrast> and <https://www.w3.org/TR/WCAG22/#focus-appearance>
+/// for non-text content.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+pub enum ContrastLevel {
+	/// Enhanced contrast for text.
+	Aaa,
+
+	/// Enhanced contrast for large text.
+	LargeAaa,
+
+	/// Minimum contrast for text.
+	Aa,
+
+	/// Minimum contrast for large text.
+	LargeAa,
+
+	/// Minimum contrast for user interface components and graphical objects.
+	UiComponent,
+
+	/// Minimum contrast between the focused and unfocused states of a focus indicator.
+	Focus,
+}
+
+impl Display for ContrastLevel {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match &self {
+			ContrastLevel::Aaa => "WCAG 2.0 §1.4.6 (AAA)",
+			ContrastLevel::LargeAaa => "WCAG 2.0 §1.4.6 (AAA, Large Text)",
+			ContrastLevel::Aa => "WCAG 2.0 §1.4.3 (AA)",
+			ContrastLevel::LargeAa => "WCAG 2.0 §1.4.3 (AA, Large Text)",
+			ContrastLevel::UiComponent => "WCAG 2.1 §1.4.11 (AA, UI Component)",
+			ContrastLevel::Focus => "WCAG 2.2 §2.4.13 (AAA, Focus Indicator)",
+		})
+	}
+}
+
+impl ContrastLevel {
+	const ALL: [ContrastLevel; 6] = [
+		ContrastLevel::Aaa,
+		ContrastLevel::LargeAaa,
+		ContrastLevel::Aa,
+		ContrastLevel::LargeAa,
+		ContrastLevel::UiComponent,
+		ContrastLevel::Focus,
+	];
+
+	/// Gets the minimum contrast ratio needed to reach this level.
+	pub fn min_ratio(&self) -> f64 {
+		match self {
+			ContrastLevel::Aaa => 7.0,
+			ContrastLevel::LargeAaa | ContrastLevel::Aa => 4.5,
+			ContrastLevel::LargeAa | ContrastLevel::UiComponent | ContrastLevel::Focus => 3.0,
+		}
+	}
+
+	/// Gets the short name of this level, e.g. `AA (Large Text)`.
+	/// Unlike [`Display`], this does not include the WCAG success criterion.
+	pub fn short_name(&self) -> &'static str {
+		match self {
+			ContrastLevel::Aaa => "AAA",
+			ContrastLevel::LargeAaa => "AAA (Large Text)",
+			ContrastLevel::Aa => "AA",
+			ContrastLevel::LargeAa => "AA (Large Text)",
+			ContrastLevel::UiComponent => "UI Component",
+			ContrastLevel::Focus => "Focus Indicator",
+		}
+	}
+
+	/// Gets the name of the WCAG success criterion defining this level.
+	pub fn description(&self) -> &'static str {
+		match self {
+			ContrastLevel::Aaa | ContrastLevel::LargeAaa => "1.4.6 Contrast (Enhanced)",
+			ContrastLevel::Aa | ContrastLevel::LargeAa => "1.4.3 Contrast (Minimum)",
+			ContrastLevel::UiComponent => "1.4.11 Non-text Contrast",
+			ContrastLevel::Focus => "2.4.13 Focus Appearance",
+		}
+	}
+
+	/// Checks if this level applies to text, rather than to non-text content like UI components.
+	pub fn is_text(&self) -> bool {
+		!matches!(self, ContrastLevel::UiComponent | ContrastLevel::Focus)
+	}
+
+	/// Checks if the contrast ratio reaches this level, ignoring floating point errors, see [`RATIO_TOLERANCE`].
+	fn is_reached_by(&self, ratio: f64) -> bool {
+		ratio * (1.0 + RATIO_TOLERANCE) >= self.min_ratio()
+	}
+
+	/// Gets all levels reached by the given contrast ratio.
+	/// Floating point errors are ignored the same way as by [`format_contrast_ratio`].
+	pub fn for_ratio(ratio: f64) -> impl Iterator<Item = ContrastLevel> {
+		ContrastLevel::ALL
+			.into_iter()
+			.filter(move |level| level.is_reached_by(ratio))
+	}
+}
+
+/// Calculates the relative luminance of a color as defined by WCAG, ranging from 0 for black to 1 for white.
+/// See <https://www.w3.org/TR/WCAG21/#dfn-relative-luminance>.
+pub fn relative_luminance(color: &Rgb) -> f32 {
+	color.relative_luminance().luma
+}
+
+/// Sorts colors by their relative luminance from dark to light, see [`relative_luminance`].
+/// The sort is stable, so colors with the same luminance keep their order.
+pub fn sort_by_luminance(colors: &mut [Rgb]) {
+	colors.sort_by(|color_1, color_2| {
+		relative_luminance(color_1).total_cmp(&relative_luminance(color_2))
+	});
+}
+
+fn levels_reached(color_1: &Rgb, color_2: &Rgb) -> HashSet<ContrastLevel> {
+	ContrastLevel::for_ratio(f64::from(contrast_ratio_val(color_1, color_2))).collect()
+}
+
+/// Calculates the contrast ratio of the two colors and which contrast levels are reached by it.
+pub fn contrast_ratio_levels_reached(color_1: &Rgb, color_2: &Rgb) -> ContrastReport {
+	ContrastReport::new(color_1, color_2)
+}
+
+/// Calculates the WCAG contrast ratio of two colors, ranging from 1 to 21.
+/// The same is available as a method through palette's [`Wcag21RelativeContrast::relative_contrast`].
+pub fn contrast_ratio_val(color_1: &Rgb, color_2: &Rgb) -> f32 {
+	color_1.relative_contrast(*color_2)
+}
+
+/// Calculates the WCAG contrast ratio of two colors, ignoring alpha.
+/// See [`contrast_ratio_val`].
+pub fn contrast_ratio_val_srgba(a: &Srgba, b: &Srgba) -> f32 {
+	contrast_ratio_val(&a.without_alpha(), &b.without_alpha())
+}
+
+/// Calculates the contrast ratio of the two colors and which contrast levels are reached by it, ignoring alpha.
+/// See [`contrast_ratio_levels_reached`].
+pub fn contrast_ratio_levels_reached_srgba(a: &Srgba, b: &Srgba) -> ContrastReport {
+	contrast_ratio_levels_reached(&a.without_alpha(), &b.without_alpha())
+}
+
+/// Relative tolerance for floating point errors in contrast ratios, e.g. `20.999998` for black on white.
+/// It is applied both when checking levels and when formatting ratios, so that both agree.
+const RATIO_TOLERANCE: f64 = f32::EPSILON as f64 * 4.0;
+
+/// Formats a contrast ratio with two decimal places in the usual notation, e.g. `4.50:1`.
+/// The ratio is rounded down, so that a ratio just below a level's threshold is not shown as reaching it.
+/// Floating point errors (e.g. `20.999998` for black on white) are ignored.
+pub fn format_contrast_ratio(ratio: f32) -> String {
+	format_contrast_ratio_with_precision(ratio, 2)
+}
+
+/// Like [`format_contrast_ratio`], but with `precision` decimal places.
+/// Precisions above [`MAX_DECIMAL_PRECISION`] format the ratio without rounding.
+pub fn format_contrast_ratio_with_precision(ratio: f32, precision: u8) -> String {
+	if precision > MAX_DECIMAL_PRECISION {
+		return format!("{}:1", ratio);
+	}
+	let ratio = f64::from(ratio) * (1.0 + RATIO_TOLERANCE);
+	format!(
+		"{:.*}:1",
+		usize::from(precision),
+		floor_n_decimals(ratio, precision)
+	)
+}
+
+/// Contrast ratio of two colors and the contrast levels reached by it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ContrastReport {
+	pub ratio: f32,
+	pub levels: HashSet<ContrastLevel>,
+}
+
+impl ContrastReport {
+	pub fn new(color_1: &Rgb, color_2: &Rgb) -> Self {
+		ContrastReport {
+			ratio: contrast_ratio_val(color_1, color_2),
+			levels: levels_reached(color_1, color_2),
+		}
+	}
+
+	/// Creates the reports of the color against white and against black, in that order, ignoring alpha.
+	pub fn for_color(color: &Srgba) -> (Self, Self) {
+		(
+			contrast_ratio_levels_reached_srgba(color, &WHITE),
+			contrast_ratio_levels_reached_srgba(color, &BLACK),
+		)
+	}
+
+	/// Checks if [`ContrastLevel::Aa`] is reached.
+	pub fn passes_aa(&self) -> bool {
+		self.levels.contains(&ContrastLevel::Aa)
+	}
+
+	/// Checks if [`ContrastLevel::Aaa`] is reached.
+	pub fn passes_aaa(&self) -> bool {
+		self.levels.contains(&ContrastLevel::Aaa)
+	}
+
+	/// Checks if [`ContrastLevel::LargeAa`] is reached.
+	pub fn passes_large_aa(&self) -> bool {
+		self.levels.contains(&ContrastLevel::LargeAa)
+	}
+
+	/// Checks if [`ContrastLevel::LargeAaa`] is reached.
+	pub fn passes_large_aaa(&self) -> bool {
+		self.levels.contains(&ContrastLevel::LargeAaa)
+	}
+
+	/// Formats the ratio, see [`format_contrast_ratio`].
+	pub fn ratio_formatted(&self) -> String {
+		format_contrast_ratio(self.ratio)
+	}
+
+	/// Formats the ratio, see [`format_contrast_ratio_with_precision`].
+	pub fn ratio_formatted_with_precision(&self, precision: u8) -> String {
+		format_contrast_ratio_with_precision(self.ratio, precision)
+	}
+}
+
+/// Returns whichever of black and white has the higher contrast to the color, ignoring alpha.
+/// Black is preferred if both have the same contrast.
+pub fn best_readable_background(color: &Srgba) -> Srgba {
+	if contrast_ratio_val_srgba(color, &WHITE) > contrast_ratio_val_srgba(color, &BLACK) {
+		WHITE
+	} else {
+		BLACK
+	}
+}
+
+/// Creates a [`ContrastReport`] for each pair of colors.
+///
+/// If the `rayon` feature is enabled, pairs are processed in parallel.
+pub fn batch_contrast_check(pairs: &[(Rgb, Rgb)]) -> Vec<ContrastReport> {
+	#[cfg(feature = "rayon")]
+	let iter = pairs.par_iter();
+	#[cfg(not(feature = "rayon"))]
+	let iter = pairs.iter();
+
+	iter.map(|(color_1, color_2)| ContrastReport::new(color_1, color_2))
+		.collect()
+}
+
+/// Checks the contrast of a foreground color against a background color.
+///
+/// Alpha channels are ignored unless the colors are composited first,
+/// e.g. using [`ContrastChecker::with_simulated_alpha_on_white`].
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ContrastChecker {
+	pub foreground: Srgba,
+	pub background: Srgba,
+}
+
+impl ContrastChecker {
+	pub fn new(foreground: Srgba, background: Srgba) -> Self {
+		ContrastChecker {
+			foreground,
+			background,
+		}
+	}
+
+	/// Calculates the WCAG contrast ratio between the colors.
+	pub fn ratio(&self) -> f32 {
+		self.foreground
+			.without_alpha()
+			.relative_contrast(self.background.without_alpha())
+	}
+
+	/// Checks if the contrast ratio reaches the given level.
+	pub fn meets(&self, level: ContrastLevel) -> bool {
+		level.is_reached_by(f64::from(self.ratio()))
+	}
+
+	/// Calculates how much contrast ratio is missing to reach the given level.
+	/// Zero if the level is already reached.
+	pub fn missing_ratio_for(&self, level: ContrastLevel) -> f32 {
+		if self.meets(level) {
+			return 0.0;
+		}
+		(level.min_ratio() - f64::from(self.ratio())) as f32
+	}
+
+	/// Composites the background on top of white, and the foreground on top of the result,
+	/// mirroring how (semi-)transparent colors are displayed on a white page.
+	pub fn with_simulated_alpha_on_white(&self) -> Self {
+		let background = self.background.over(WHITE);
+		let foreground = self.foreground.over(background);
+		ContrastChecker::new(foreground, background)
+	}
+}
+
+/// Number of Oklab lightness steps between 0 and 1 tried when searching for a color.
+const LIGHTNESS_STEP_COUNT: u32 = 100;
+
+/// Finds the foreground color closest to `fg` that reaches the given contrast level against `bg`,
+/// by walking the Oklab lightness of `fg` up or down, while keeping its hue and chroma.
+/// If `fg` already reaches the level, it is returned unchanged. If no lightness reaches the level,
+/// black or white is returned, whichever has the higher contrast.
+pub fn nearest_color_for_level(fg: &Rgb, bg: &Rgb, level: ContrastLevel) -> Rgb {
+	if level.is_reached_by(f64::from(fg.relative_contrast(*bg))) {
+		return *fg;
+	}
+
+	let fg_oklab: Oklab = (*fg).into_color();
+	for step in 1..=LIGHTNESS_STEP_COUNT {
+		let offset = step as f32 / LIGHTNESS_STEP_COUNT as f32;
+		// Prefer the direction with less change, trying both for each distance.
+		for lightness in [fg_oklab.l + offset, fg_oklab.l - offset] {
+			if !(0.0..=1.0).contains(&lightness) {
+				continue;
+			}
+			let candidate = Rgb::from_color(Oklab::new(lightness, fg_oklab.a, fg_oklab.b));
+			if level.is_reached_by(f64::from(candidate.relative_contrast(*bg))) {
+				return candidate;
+			}
+		}
+	}
+
+	if BLACK.color.relative_contrast(*bg) > WHITE.color.relative_contrast(*bg) {
+		BLACK.color
+	} else {
+		WHITE.color
+	}
+}
+
+/// Finds the foreground color closest to `fg` that reaches [`ContrastLevel::Aa`] against `bg`.
+/// See [`nearest_color_for_level`].
+pub fn nearest_aa_color(fg: &Rgb, bg: &Rgb) -> Rgb {
+	nearest_color_for_level(fg, bg, ContrastLevel::Aa)
+}
+
+/// Finds the foreground color closest to `fg` that reaches [`ContrastLevel::Aaa`] against `bg`.
+/// See [`nearest_color_for_level`].
+pub fn nearest_aaa_color(fg: &Rgb, bg: &Rgb) -> Rgb {
+	nearest_color_for_level(fg, bg, ContrastLevel::Aaa)
+}
+
+/// Usage tiers for APCA lightness contrast values, based on
+/// <https://git.apcacontrast.com/documentation/APCA_in_a_Nutshell>.
+///
+/// Positive values apply to dark text on a light background, negative values to light text on a dark background.
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+pub enum ApcaLevel {
+	/// Minimum for content text, light on dark.
+	LcMinus60,
+
+	/// Minimum for large text and headlines, light on dark.
+	LcMinus45,
+
+	/// Minimum for large text and headlines.
+	Lc45,
+
+	/// Minimum for content text.
+	Lc60,
+
+	/// Minimum for body text.
+	Lc75,
+
+	/// Preferred for body text.
+	Lc90,
+}
+
+impl ApcaLevel {
+	/// Finds the highest tier reached by an APCA lightness contrast value.
+	pub fn for_contrast(lightness_contrast: f64) -> Option<ApcaLevel> {
+		match lightness_contrast {
+			lc if lc >= 90.0 => Some(ApcaLevel::Lc90),
+			lc if lc >= 75.0 => Some(ApcaLevel::Lc75),
+			lc if lc >= 60.0 => Some(ApcaLevel::Lc60),
+			lc if lc >= 45.0 => Some(ApcaLevel::Lc45),
+			lc if lc <= -60.0 => Some(ApcaLevel::LcMinus60),
+			lc if lc <= -45.0 => Some(ApcaLevel::LcMinus45),
+			_ => None,
+		}
+	}
+}
+
+impl Display for ApcaLevel {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match &self {
+			ApcaLevel::LcMinus60 => "Lc -60 (Content Text)",
+			ApcaLevel::LcMinus45 => "Lc -45 (Large Text)",
+			ApcaLevel::Lc45 => "Lc 45 (Large Text)",
+			ApcaLevel::Lc60 => "Lc 60 (Content Text)",
+			ApcaLevel::Lc75 => "Lc 75 (Body Text)",
+			ApcaLevel::Lc90 => "Lc 90 (Preferred Body Text)",
+		})
+	}
+}
+
+// Constants of APCA version 0.0.98G-4g.
+const APCA_MAIN_TRC: f64 = 2.4;
+const APCA_RED_COEFFICIENT: f64 = 0.2126729;
+const APCA_GREEN_COEFFICIENT: f64 = 0.7151522;
+const APCA_BLUE_COEFFICIENT: f64 = 0.0721750;
+const APCA_BLACK_THRESHOLD: f64 = 0.022;
+const APCA_BLACK_CLAMP: f64 = 1.414;
+const APCA_NORMAL_BACKGROUND_EXPONENT: f64 = 0.56;
+const APCA_NORMAL_TEXT_EXPONENT: f64 = 0.57;
+const APCA_REVERSE_BACKGROUND_EXPONENT: f64 = 0.65;
+const APCA_REVERSE_TEXT_EXPONENT: f64 = 0.62;
+const APCA_SCALE: f64 = 1.14;
+const APCA_OFFSET: f64 = 0.027;
+const APCA_LOW_CLIP: f64 = 0.1;
+const APCA_DELTA_Y_MIN: f64 = 0.0005;
+
+fn apca_luminance(color: &Rgb) -> f64 {
+	let luminance = APCA_RED_COEFFICIENT * f64::from(color.red).powf(APCA_MAIN_TRC)
+		+ APCA_GREEN_COEFFICIENT * f64::from(color.green).powf(APCA_MAIN_TRC)
+		+ APCA_BLUE_COEFFICIENT * f64::from(color.blue).powf(APCA_MAIN_TRC);
+
+	// Soft clamp for very dark colors.
+	if luminance < APCA_BLACK_THRESHOLD {
+		luminance + (APCA_BLACK_THRESHOLD - luminance).powf(APCA_BLACK_CLAMP)
+	} else {
+		luminance
+	}
+}
+
+/// Calculates the APCA lightness contrast (Lc) of text on a background, as proposed for WCAG 3.0.
+/// See <https://github.com/Myndex/SAPC-APCA>.
+///
+/// Unlike the WCAG 2.x contrast ratio, the order of colors matters: The result is positive
+/// for dark text on a light background, and negative for light text on a dark background.
+/// Values range from about -108 to 106.
+pub fn apca_contrast(text: &Rgb, background: &Rgb) -> f64 {
+	let text_luminance = apca_luminance(text);
+	let background_luminance = apca_luminance(background);
+
+	if (background_luminance - text_luminance).abs() < APCA_DELTA_Y_MIN {
+		return 0.0;
+	}
+
+	let lightness_contrast = if background_luminance > text_luminance {
+		let contrast = (background_luminance.powf(APCA_NORMAL_BACKGROUND_EXPONENT)
+			- text_luminance.powf(APCA_NORMAL_TEXT_EXPONENT))
+			* APCA_SCALE;
+		if contrast < APCA_LOW_CLIP {
+			0.0
+		} else {
+			contrast - APCA_OFFSET
+		}
+	} else {
+		let contrast = (background_luminance.powf(APCA_REVERSE_BACKGROUND_EXPONENT)
+			- text_luminance.powf(APCA_REVERSE_TEXT_EXPONENT))
+			* APCA_SCALE;
+		if contrast > -APCA_LOW_CLIP {
+			0.0
+		} else {
+			contrast + APCA_OFFSET
+		}
+	};
+	lightness_contrast * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn relative_luminance_known_values() {
+		assert_eq!(relative_luminance(&Rgb::new(0.0, 0.0, 0.0)), 0.0);
+		assert!((relative_luminance(&Rgb::new(1.0, 1.0, 1.0)) - 1.0).abs() < 0.001);
+
+		let gray: Rgb = Rgb::<_, u8>::new(0x80, 0x80, 0x80).into_format();
+		assert!((relative_luminance(&gray) - 0.216).abs() < 0.001);
+	}
+
+	#[test]
+	fn sort_by_luminance_sorts_dark_to_light() {
+		let black = Rgb::new(0.0, 0.0, 0.0);
+		let white = Rgb::new(1.0, 1.0, 1.0);
+		let green = Rgb::new(0.0, 1.0, 0.0);
+		let mut colors = vec![white, green, black];
+
+		sort_by_luminance(&mut colors);
+		assert_eq!(colors, vec![black, green, white]);
+	}
+
+	#[test]
+	fn sort_by_luminance_keeps_order_of_equal_luminance() {
+		let color_1 = Rgb::new(0.2, 0.4, 0.6);
+		let color_2 = Rgb::new(0.2, 0.4, 0.6);
+		let black = Rgb::new(0.0, 0.0, 0.0);
+		let mut colors = vec![color_1, black, color_2];
+
+		sort_by_luminance(&mut colors);
+		assert_eq!(colors, vec![black, color_1, color_2]);
+	}
+
+	#[test]
+	fn contrast_level_min_ratio() {
+		assert_eq!(ContrastLevel::Aaa.min_ratio(), 7.0);
+		assert_eq!(ContrastLevel::LargeAaa.min_ratio(), 4.5);
+		assert_eq!(ContrastLevel::Aa.min_ratio(), 4.5);
+		assert_eq!(ContrastLevel::LargeAa.min_ratio(), 3.0);
+		assert_eq!(ContrastLevel::UiComponent.min_ratio(), 3.0);
+		assert_eq!(ContrastLevel::Focus.min_ratio(), 3.0);
+	}
+
+	#[test]
+	fn contrast_level_is_text() {
+		assert!(ContrastLevel::Aa.is_text());
+		assert!(!ContrastLevel::UiComponent.is_text());
+		assert!(!ContrastLevel::Focus.is_text());
+	}
+
+	#[test]
+	fn contrast_level_display_includes_criterion() {
+		assert_eq!(ContrastLevel::Aaa.to_string(), "WCAG 2.0 §1.4.6 (AAA)");
+		assert_eq!(
+			ContrastLevel::LargeAa.to_string(),
+			"WCAG 2.0 §1.4.3 (AA, Large Text)"
+		);
+		assert_eq!(ContrastLevel::LargeAa.short_name(), "AA (Large Text)");
+	}
+
+	#[test]
+	fn contrast_level_for_ratio() {
+		assert_eq!(
+			ContrastLevel::for_ratio(5.0).collect::<HashSet<_>>(),
+			HashSet::from([
+				ContrastLevel::LargeAaa,
+				ContrastLevel::Aa,
+				ContrastLevel::LargeAa,
+				ContrastLevel::UiComponent,
+				ContrastLevel::Focus
+			])
+		);
+		assert_eq!(ContrastLevel::for_ratio(2.0).count(), 0);
+	}
+
+	#[test]
+	fn contrast_ratio_levels_reached_finds_levels() {
+		let black = Rgb::new(0.0, 0.0, 0.0);
+		let white = Rgb::new(1.0, 1.0, 1.0);
+		assert_eq!(
+			contrast_ratio_levels_reached(&black, &white).levels,
+			HashSet::from([
+				ContrastLevel::Aaa,
+				ContrastLevel::LargeAaa,
+				ContrastLevel::Aa,
+				ContrastLevel::LargeAa,
+				ContrastLevel::UiComponent,
+				ContrastLevel::Focus
+			])
+		);
+
+		assert!(contrast_ratio_levels_reached(&white, &white)
+			.levels
+			.is_empty());
+	}
+
+	#[test]
+	fn contrast_ratio_val_is_symmetric() {
+		let color_1 = Rgb::new(0.2, 0.4, 0.6);
+		let color_2 = Rgb::new(0.9, 0.9, 0.8);
+
+		assert_eq!(
+			contrast_ratio_val(&color_1, &color_2),
+			contrast_ratio_val(&color_2, &color_1)
+		);
+		assert!(
+			(contrast_ratio_val(&Rgb::new(0.0, 0.0, 0.0), &Rgb::new(1.0, 1.0, 1.0)) - 21.0).abs()
+				< 0.01
+		);
+	}
+
+	#[test]
+	fn contrast_ratio_srgba_ignores_alpha() {
+		let black = Srgba::new(0.0, 0.0, 0.0, 0.5);
+		let white = Srgba::new(1.0, 1.0, 1.0, 1.0);
+
+		assert!((contrast_ratio_val_srgba(&black, &white) - 21.0).abs() < 0.01);
+		assert_eq!(
+			contrast_ratio_levels_reached_srgba(&black, &white),
+			contrast_ratio_levels_reached(&black.without_alpha(), &white.without_alpha())
+		);
+	}
+
+	#[test]
+	fn contrast_checker_ratio() {
+		let checker = ContrastChecker::new(
+			Srgba::new(0.0, 0.0, 0.0, 1.0),
+			Srgba::new(1.0, 1.0, 1.0, 1.0),
+		);
+
+		assert!((checker.ratio() - 21.0).abs() < 0.01);
+		assert!(checker.meets(ContrastLevel::Aaa));
+		assert_eq!(checker.missing_ratio_for(ContrastLevel::Aaa), 0.0);
+	}
+
+	#[test]
+	fn contrast_checker_missing_ratio() {
+		let checker = ContrastChecker::new(
+			Srgba::new(1.0, 1.0, 1.0, 1.0),
+			Srgba::new(1.0, 1.0, 1.0, 1.0),
+		);
+
+		assert_eq!(checker.ratio(), 1.0);
+		assert!(!checker.meets(ContrastLevel::LargeAa));
+		assert_eq!(checker.missing_ratio_for(ContrastLevel::LargeAa), 2.0);
+		assert_eq!(checker.missing_ratio_for(ContrastLevel::Aaa), 6.0);
+	}
+
+	#[test]
+	fn contrast_checker_with_simulated_alpha_on_white() {
+		let checker = ContrastChecker::new(
+			Srgba::new(0.0, 0.0, 0.0, 0.0),
+			Srgba::new(0.0, 0.0, 0.0, 0.0),
+		)
+		.with_simulated_alpha_on_white();
+
+		assert_eq!(checker.foreground, Srgba::new(1.0, 1.0, 1.0, 1.0));
+		assert_eq!(checker.background, Srgba::new(1.0, 1.0, 1.0, 1.0));
+		assert_eq!(checker.ratio(), 1.0);
+	}
+
+	#[test]
+	fn contrast_report_passes() {
+		let report = ContrastReport::new(&Rgb::new(0.0, 0.0, 0.0), &Rgb::new(0.5, 0.5, 0.5));
+
+		assert!(report.passes_large_aa());
+		assert!(report.passes_aa());
+		assert!(report.passes_large_aaa());
+		assert!(!report.passes_aaa());
+	}
+
+	#[test]
+	fn format_contrast_ratio_uses_two_decimals() {
+		assert_eq!(format_contrast_ratio(4.5), "4.50:1");
+		assert_eq!(format_contrast_ratio(3.0), "3.00:1");
+	}
+
+	#[test]
+	fn format_contrast_ratio_does_not_round_up_to_threshold() {
+		let ratio = 4.49996;
+
+		assert!(!ContrastLevel::for_ratio(f64::from(ratio)).any(|level| level == ContrastLevel::Aa));
+		assert_eq!(format_contrast_ratio(ratio), "4.49:1");
+	}
+
+	#[test]
+	fn format_contrast_ratio_agrees_with_levels_below_threshold() {
+		// One ulp below 4.5.
+		let ratio = f32::from_bits(4.5_f32.to_bits() - 1);
+
+		assert!(ContrastLevel::for_ratio(f64::from(ratio)).any(|level| level == ContrastLevel::Aa));
+		assert_eq!(format_contrast_ratio(ratio), "4.50:1");
+	}
+
+	#[test]
+	fn format_contrast_ratio_with_precision_uses_precision() {
+		assert_eq!(format_contrast_ratio_with_precision(4.567, 0), "4:1");
+		assert_eq!(format_contrast_ratio_with_precision(4.567, 1), "4.5:1");
+		assert_eq!(
+			format_contrast_ratio_with_precision(4.567, u8::MAX),
+			"4.567:1"
+		);
+	}
+
+	#[test]
+	fn contrast_report_ratio_formatted() {
+		let report = ContrastReport {
+			ratio: 4.499,
+			levels: HashSet::new(),
+		};
+		assert_eq!(report.ratio_formatted(), "4.49:1");
+
+		let report = ContrastReport::new(&Rgb::new(0.0, 0.0, 0.0), &Rgb::new(1.0, 1.0, 1.0));
+		assert_eq!(report.ratio_formatted(), "21.00:1");
+	}
+
+	#[test]
+	fn contrast_report_for_color_checks_white_and_black() {
+		let (against_white, against_black) =
+			ContrastReport::for_color(&Srgba::new(0.0, 0.0, 0.0, 1.0));
+
+		assert_eq!(against_white.ratio_formatted(), "21.00:1");
+		assert_eq!(against_black.ratio_formatted(), "1.00:1");
+	}
+
+	#[test]
+	fn best_readable_background_finds_result() {
+		assert_eq!(
+			best_readable_background(&Srgba::new(0.9, 0.85, 1.0, 1.0)),
+			BLACK
+		);
+		assert_eq!(
+			best_readable_background(&Srgba::new(0.0, 0.1, 0.25, 1.0)),
+			WHITE
+		);
+	}
+
+	#[test]
+	fn batch_contrast_check_matches_single_check() {
+		let pairs = (0..100)
+			.map(|i| {
+				let val = i as f32 / 100.0;
+				(
+					Rgb::new(val, 0.5, 1.0 - val),
+					Rgb::new(1.0 - val, val, 0.25),
+				)
+			})
+			.collect::<Vec<_>>();
+
+		let result = batch_contrast_check(&pairs);
+		let expected = pairs
+			.iter()
+			.map(|(color_1, color_2)| ContrastReport::new(color_1, color_2))
+			.collect::<Vec<_>>();
+		assert_eq!(result, expected);
+	}
+
+	#[test]
+	fn nearest_aa_color_unchanged_if_reached() {
+		let black = Rgb::new(0.0, 0.0, 0.0);
+		let white = Rgb::new(1.0, 1.0, 1.0);
+
+		assert_eq!(nearest_aa_color(&black, &white), black);
+	}
+
+	#[test]
+	fn nearest_aa_color_darkens_on_light_background() {
+		let gray: Rgb = Rgb::<_, u8>::new(0x88, 0x88, 0x88).into_format();
+		let white = Rgb::new(1.0, 1.0, 1.0);
+
+		let result = nearest_aa_color(&gray, &white);
+		assert!(result.relative_contrast(white) >= 4.5);
+		assert!(result.red < gray.red);
+		// Only the smallest necessary step is taken.
+		assert!(result.relative_contrast(white) < 4.8);
+	}
+
+	#[test]
+	fn nearest_aaa_color_reaches_level() {
+		let blue = Rgb::new(0.3, 0.5, 0.9);
+		let black = Rgb::new(0.0, 0.0, 0.0);
+
+		let result = nearest_aaa_color(&blue, &black);
+		assert!(result.relative_contrast(black) >= 7.0);
+	}
+
+	#[test]
+	fn apca_contrast_known_values() {
+		let black = Rgb::new(0.0, 0.0, 0.0);
+		let white = Rgb::new(1.0, 1.0, 1.0);
+		let gray: Rgb = Rgb::<_, u8>::new(0x88, 0x88, 0x88).into_format();
+
+		assert!((apca_contrast(&black, &white) - 106.04).abs() < 0.01);
+		assert!((apca_contrast(&white, &black) + 107.88).abs() < 0.01);
+		assert!((apca_contrast(&gray, &white) - 63.06).abs() < 0.01);
+		assert_eq!(apca_contrast(&gray, &gray), 0.0);
+	}
+
+	#[test]
+	fn apca_level_for_contrast() {
+		assert_eq!(ApcaLevel::for_contrast(106.0), Some(ApcaLevel::Lc90));
+		assert_eq!(ApcaLevel::for_contrast(63.0), Some(ApcaLevel::Lc60));
+		assert_eq!(ApcaLevel::for_contrast(30.0), None);
+		assert_eq!(ApcaLevel::for_contrast(-50.0), Some(ApcaLevel::LcMinus45));
+		assert_eq!(ApcaLevel::for_contrast(-107.0), Some(ApcaLevel::LcMinus60));
+	}
+}
@@ -0,0 +1,105 @@
+use palette::encoding::{FromLinear, IntoLinear, Srgb};
+use palette::rgb::Rgb;
+
+type Matrix = [[f32; 3]; 3];
+
+/// Conversion from linear Display P3 to linear sRGB, combining the conversions to and from CIE XYZ,
+/// see <https://www.w3.org/TR/css-color-4/#color-conversion-code>.
+const LINEAR_DISPLAY_P3_TO_LINEAR_SRGB: Matrix = [
+	[1.224_940_2, -0.224_940_4, 0.0],
+	[-0.042_056_9, 1.042_057_1, 0.0],
+	[-0.019_637_6, -0.078_636_1, 1.098_273_5],
+];
+
+/// Inverse of [`LINEAR_DISPLAY_P3_TO_LINEAR_SRGB`].
+const LINEAR_SRGB_TO_LINEAR_DISPLAY_P3: Matrix = [
+	[0.822_462_1, 0.177_538, 0.0],
+	[0.033_194_1, 0.966_805_8, 0.0],
+	[0.017_082_7, 0.072_397_4, 0.910_519_9],
+];
+
+/// Color in the Display P3 color space, e.g. from `color(display-p3 1 0 0)`.
+/// Channels range from 0 to 1 within the Display P3 gamut.
+///
+/// This is not a palette type, as palette only supports the sRGB primaries.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct DisplayP3 {
+	pub red: f32,
+	pub green: f32,
+	pub blue: f32,
+}
+
+impl DisplayP3 {
+	pub fn new(red: f32, green: f32, blue: f32) -> Self {
+		DisplayP3 { red, green, blue }
+	}
+}
+
+fn multiply(matrix: &Matrix, channels: [f32; 3]) -> [f32; 3] {
+	matrix.map(|row| row[0] * channels[0] + row[1] * channels[1] + row[2] * channels[2])
+}
+
+// Display P3 and sRGB share the same transfer function, only their primaries differ.
+fn into_linear(channels: [f32; 3]) -> [f32; 3] {
+	channels.map(<Srgb as IntoLinear<f32, f32>>::into_linear)
+}
+
+fn from_linear(channels: [f32; 3]) -> [f32; 3] {
+	channels.map(<Srgb as FromLinear<f32, f32>>::from_linear)
+}
+
+/// Converts a color from Display P3 to sRGB.
+/// Display P3 uses the transfer function of sRGB but has a wider gamut,
+/// so the result may be outside of the sRGB gamut, see [`crate::gamut::map_to_srgb_gamut`].
+pub fn display_p3_to_srgb(color: &DisplayP3) -> Rgb {
+	let [red, green, blue] = from_linear(multiply(
+		&LINEAR_DISPLAY_P3_TO_LINEAR_SRGB,
+		into_linear([color.red, color.green, color.blue]),
+	));
+	Rgb::new(red, green, blue)
+}
+
+/// Converts a color from sRGB to Display P3, see [`display_p3_to_srgb`].
+pub fn srgb_to_display_p3(color: &Rgb) -> DisplayP3 {
+	let [red, green, blue] = from_linear(multiply(
+		&LINEAR_SRGB_TO_LINEAR_DISPLAY_P3,
+		into_linear([color.red, color.green, color.blue]),
+	));
+	DisplayP3::new(red, green, blue)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::gamut::is_in_srgb_gamut;
+
+	use super::*;
+
+	#[test]
+	fn display_p3_to_srgb_keeps_white_and_black() {
+		for value in [0.0, 1.0] {
+			let color = display_p3_to_srgb(&DisplayP3::new(value, value, value));
+
+			assert!((color.red - value).abs() < 1e-4);
+			assert!((color.green - value).abs() < 1e-4);
+			assert!((color.blue - value).abs() < 1e-4);
+		}
+	}
+
+	#[test]
+	fn display_p3_to_srgb_red_is_out_of_gamut() {
+		assert!(!is_in_srgb_gamut(&display_p3_to_srgb(&DisplayP3::new(
+			1.0, 0.0, 0.0
+		))));
+	}
+
+	#[test]
+	fn srgb_to_display_p3_round_trip() {
+		let color = Rgb::new(0.2, 0.5, 0.8);
+
+		let round_tripped = display_p3_to_srgb(&srgb_to_display_p3(&color));
+
+		assert!((round_tripped.red - color.red).abs() < 1e-4);
+		assert!((round_tripped.green - color.green).abs() < 1e-4);
+		assert!((round_tripped.blue - color.blue).abs() < 1e-4);
+	}
+}
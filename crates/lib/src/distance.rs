@@ -0,0 +1,234 @@
+use std::fmt;
+use std::fmt::Display;
+
+use palette::color_difference::{Ciede2000, DeltaE, EuclideanDistance};
+use palette::rgb::Rgb;
+use palette::white_point::D65;
+use palette::{IntoColor, Lab, Oklab};
+
+/// Calculates the perceptual difference between two colors.
+///
+/// This is the recommended way to compare colors if no specific metric is required.
+/// It is the Euclidean distance in the Oklab color space, which is perceptually uniform enough
+/// for most purposes while being cheap to calculate.
+/// A difference of `0.0` means the colors are the same, while black and white have a difference of `1.0`.
+///
+/// If a standardized metric is needed (e.g. for print or textiles), use [`delta_e_ciede2000`] instead.
+pub fn perceptual_color_distance(a: &Rgb, b: &Rgb) -> f32 {
+	let a_oklab: Oklab = (*a).into_color();
+	let b_oklab: Oklab = (*b).into_color();
+	a_oklab.distance(b_oklab)
+}
+
+/// Calculates the CIE76 color difference, which is the Euclidean distance in the Lab color space.
+pub fn delta_e_cie76(a: &Lab<D65, f32>, b: &Lab<D65, f32>) -> f32 {
+	a.delta_e(*b)
+}
+
+/// Weighting factors of the CIE94 color difference.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Cie94Params {
+	pub k_l: f32,
+	pub k_1: f32,
+	pub k_2: f32,
+}
+
+impl Cie94Params {
+	/// Weighting factors for graphic arts.
+	pub const GRAPHIC_ARTS: Cie94Params = Cie94Params {
+		k_l: 1.0,
+		k_1: 0.045,
+		k_2: 0.015,
+	};
+
+	/// Weighting factors for textiles.
+	pub const TEXTILES: Cie94Params = Cie94Params {
+		k_l: 2.0,
+		k_1: 0.048,
+		k_2: 0.014,
+	};
+}
+
+/// Calculates the CIE94 color difference, using `a` as the reference color.
+/// See <https://en.wikipedia.org/wiki/Color_difference#CIE94>.
+pub fn delta_e_cie94(a: &Lab<D65, f32>, b: &Lab<D65, f32>, params: Cie94Params) -> f32 {
+	let delta_l = a.l - b.l;
+	let chroma_a = a.a.hypot(a.b);
+	let chroma_b = b.a.hypot(b.b);
+	let delta_c = chroma_a - chroma_b;
+	let delta_a = a.a - b.a;
+	let delta_b = a.b - b.b;
+	// May become slightly negative due to rounding errors.
+	let delta_h_squared = (delta_a.powi(2) + delta_b.powi(2) - delta_c.powi(2)).max(0.0);
+
+	let s_c = 1.0 + params.k_1 * chroma_a;
+	let s_h = 1.0 + params.k_2 * chroma_a;
+
+	((delta_l / params.k_l).powi(2) + (delta_c / s_c).powi(2) + delta_h_squared / s_h.powi(2))
+		.sqrt()
+}
+
+/// Calculates the CIEDE2000 color difference.
+/// See <https://en.wikipedia.org/wiki/Color_difference#CIEDE2000>.
+pub fn delta_e_ciede2000(a: &Lab<D65, f32>, b: &Lab<D65, f32>) -> f32 {
+	a.difference(*b)
+}
+
+/// Calculates the Euclidean distance of the sRGB channels, ranging from 0 to the square root of 3 for black and white.
+/// This is not perceptually uniform, prefer [`perceptual_color_distance`] to compare colors.
+pub fn euclidean_rgb_distance(a: &Rgb, b: &Rgb) -> f32 {
+	((a.red - b.red).powi(2) + (a.green - b.green).powi(2) + (a.blue - b.blue).powi(2)).sqrt()
+}
+
+/// Qualitative description of a Delta E color difference, e.g. from [`delta_e_ciede2000`].
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Copy, Clone)]
+pub enum PerceptualDifference {
+	/// Difference below 1, not perceptible by human eyes.
+	Imperceptible,
+
+	/// Difference from 1 to 2, perceptible through close observation.
+	BarelyDistinguishable,
+
+	/// Difference from 2 to 10, perceptible at a glance.
+	Distinguishable,
+
+	/// Difference above 10, the colors are more different than similar.
+	ClearlyDifferent,
+}
+
+impl PerceptualDifference {
+	/// Finds the description for a Delta E color difference.
+	pub fn for_delta_e(delta_e: f32) -> PerceptualDifference {
+		match delta_e {
+			delta_e if delta_e < 1.0 => PerceptualDifference::Imperceptible,
+			delta_e if delta_e < 2.0 => PerceptualDifference::BarelyDistinguishable,
+			delta_e if delta_e <= 10.0 => PerceptualDifference::Distinguishable,
+			_ => PerceptualDifference::ClearlyDifferent,
+		}
+	}
+}
+
+impl Display for PerceptualDifference {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match &self {
+			PerceptualDifference::Imperceptible => "imperceptible",
+			PerceptualDifference::BarelyDistinguishable => "barely distinguishable",
+			PerceptualDifference::Distinguishable => "distinguishable",
+			PerceptualDifference::ClearlyDifferent => "clearly different",
+		})
+	}
+}
+
+/// Finds the color in `colors` that is perceptually closest to `target` using CIEDE2000.
+/// Returns `None` if `colors` is empty.
+pub fn nearest_color<'a>(target: &Rgb, colors: &'a [Rgb]) -> Option<&'a Rgb> {
+	let target_lab: Lab<D65, f32> = (*target).into_color();
+	colors.iter().min_by(|x, y| {
+		let x_lab: Lab<D65, f32> = (**x).into_color();
+		let y_lab: Lab<D65, f32> = (**y).into_color();
+		delta_e_ciede2000(&target_lab, &x_lab).total_cmp(&delta_e_ciede2000(&target_lab, &y_lab))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn perceptual_color_distance_same_color() {
+		let color = Rgb::new(0.2, 0.4, 0.6);
+
+		assert_eq!(perceptual_color_distance(&color, &color), 0.0);
+	}
+
+	#[test]
+	fn perceptual_color_distance_black_white() {
+		let black = Rgb::new(0.0, 0.0, 0.0);
+		let white = Rgb::new(1.0, 1.0, 1.0);
+
+		assert!((perceptual_color_distance(&black, &white) - 1.0).abs() < 0.001);
+	}
+
+	// Test pair 1 of <https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/>.
+	const SHARMA_LAB_A: Lab<D65, f32> = Lab::new(50.0, 2.6772, -79.7751);
+	const SHARMA_LAB_B: Lab<D65, f32> = Lab::new(50.0, 0.0, -82.7485);
+
+	#[test]
+	fn delta_e_cie76_known_value() {
+		assert!((delta_e_cie76(&SHARMA_LAB_A, &SHARMA_LAB_B) - 4.0011).abs() < 0.001);
+	}
+
+	#[test]
+	fn delta_e_cie94_known_values() {
+		assert!(
+			(delta_e_cie94(&SHARMA_LAB_A, &SHARMA_LAB_B, Cie94Params::GRAPHIC_ARTS) - 1.3950).abs()
+				< 0.001
+		);
+		assert!(
+			(delta_e_cie94(&SHARMA_LAB_A, &SHARMA_LAB_B, Cie94Params::TEXTILES) - 1.4230).abs()
+				< 0.001
+		);
+	}
+
+	#[test]
+	fn delta_e_ciede2000_known_value() {
+		assert!((delta_e_ciede2000(&SHARMA_LAB_A, &SHARMA_LAB_B) - 2.0425).abs() < 0.001);
+	}
+
+	#[test]
+	fn nearest_color_finds_closest() {
+		let colors = [
+			Rgb::new(1.0, 0.0, 0.0),
+			Rgb::new(0.0, 1.0, 0.0),
+			Rgb::new(0.0, 0.0, 1.0),
+		];
+
+		assert_eq!(
+			nearest_color(&Rgb::new(0.1, 0.2, 0.8), &colors),
+			Some(&colors[2])
+		);
+		assert_eq!(nearest_color(&Rgb::new(0.1, 0.2, 0.8), &[]), None);
+	}
+
+	#[test]
+	fn perceptual_color_distance_is_perceptual() {
+		let black = Rgb::new(0.0, 0.0, 0.0);
+		let dark_gray = Rgb::new(0.1, 0.1, 0.1);
+		let white = Rgb::new(1.0, 1.0, 1.0);
+		let light_gray = Rgb::new(0.9, 0.9, 0.9);
+
+		// Differences in dark colors are more visible than the same differences in light colors.
+		assert!(
+			perceptual_color_distance(&black, &dark_gray)
+				> perceptual_color_distance(&white, &light_gray)
+		);
+	}
+
+	#[test]
+	fn euclidean_rgb_distance_black_white() {
+		let black = Rgb::new(0.0, 0.0, 0.0);
+		let white = Rgb::new(1.0, 1.0, 1.0);
+
+		assert_eq!(euclidean_rgb_distance(&black, &white), 3.0_f32.sqrt());
+	}
+
+	#[test]
+	fn perceptual_difference_for_delta_e() {
+		assert_eq!(
+			PerceptualDifference::for_delta_e(0.5),
+			PerceptualDifference::Imperceptible
+		);
+		assert_eq!(
+			PerceptualDifference::for_delta_e(1.5),
+			PerceptualDifference::BarelyDistinguishable
+		);
+		assert_eq!(
+			PerceptualDifference::for_delta_e(10.0),
+			PerceptualDifference::Distinguishable
+		);
+		assert_eq!(
+			PerceptualDifference::for_delta_e(50.0),
+			PerceptualDifference::ClearlyDifferent
+		);
+	}
+}
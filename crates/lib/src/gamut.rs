@@ -0,0 +1,143 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+
+use palette::rgb::Rgb;
+use palette::{Clamp, FromColorUnclamped, Oklab, Oklch};
+
+/// Tolerance for channels slightly out of range due to floating point errors during conversion.
+const GAMUT_TOLERANCE: f32 = 1e-5;
+
+/// Just noticeable difference in Oklab used by the gamut mapping, see [`map_to_srgb_gamut`].
+const JND: f32 = 0.02;
+
+/// Precision of the chroma search of the gamut mapping.
+const CHROMA_EPSILON: f32 = 0.0001;
+
+/// Error for a color outside of the sRGB gamut, holding the unclamped color.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct RangeError(pub Rgb);
+
+impl Display for RangeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("Color is outside of the sRGB gamut.")
+	}
+}
+
+impl Error for RangeError {}
+
+/// Checks if all channels of the color are in the range 0 to 1, i.e. if it can be displayed in sRGB.
+/// Colors converted from e.g. Lab or Oklch may be out of gamut.
+/// Deviations caused by floating point errors are ignored.
+pub fn is_in_srgb_gamut(color: &Rgb) -> bool {
+	[color.red, color.green, color.blue]
+		.iter()
+		.all(|channel| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(channel))
+}
+
+/// Clips each channel of the color to the range 0 to 1 individually, like palette's [`Clamp::clamp`].
+/// This is cheaper than [`map_to_srgb_gamut`] and fine for small errors, e.g. after blending,
+/// but may noticeably shift hue and lightness for colors far outside of the gamut.
+pub fn clip_to_srgb_gamut(color: &Rgb) -> Rgb {
+	(*color).clamp()
+}
+
+fn delta_e_ok(color_1: &Rgb, color_2: &Rgb) -> f32 {
+	let oklab_1 = Oklab::from_color_unclamped(*color_1);
+	let oklab_2 = Oklab::from_color_unclamped(*color_2);
+	((oklab_1.l - oklab_2.l).powi(2)
+		+ (oklab_1.a - oklab_2.a).powi(2)
+		+ (oklab_1.b - oklab_2.b).powi(2))
+	.sqrt()
+}
+
+/// Maps the color into the sRGB gamut by reducing its chroma in Oklch until it is in gamut
+/// or clipping it is no longer noticeable, keeping lightness and hue.
+/// Colors already in gamut are returned unchanged.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#binsearch).
+pub fn map_to_srgb_gamut(color: &Rgb) -> Rgb {
+	if is_in_srgb_gamut(color) {
+		return *color;
+	}
+
+	let mut current = Oklch::from_color_unclamped(*color);
+	if current.l >= 1.0 {
+		return Rgb::new(1.0, 1.0, 1.0);
+	}
+	if current.l <= 0.0 {
+		return Rgb::new(0.0, 0.0, 0.0);
+	}
+
+	let mut clipped = (*color).clamp();
+	if delta_e_ok(&clipped, color) < JND {
+		return clipped;
+	}
+
+	let mut min_chroma = 0.0;
+	let mut max_chroma = current.chroma;
+	let mut min_in_gamut = true;
+	while max_chroma - min_chroma > CHROMA_EPSILON {
+		current.chroma = (min_chroma + max_chroma) / 2.0;
+		let current_rgb = Rgb::from_color_unclamped(current);
+
+		if min_in_gamut && is_in_srgb_gamut(&current_rgb) {
+			min_chroma = current.chroma;
+			continue;
+		}
+
+		clipped = current_rgb.clamp();
+		let delta_e = delta_e_ok(&clipped, &current_rgb);
+		if delta_e < JND {
+			if JND - delta_e < CHROMA_EPSILON {
+				return clipped;
+			}
+			min_in_gamut = false;
+			min_chroma = current.chroma;
+		} else {
+			max_chroma = current.chroma;
+		}
+	}
+	clipped
+}
+
+#[cfg(test)]
+mod tests {
+	use palette::{IntoColorUnclamped, Lch};
+
+	use super::*;
+
+	#[test]
+	fn is_in_srgb_gamut_checks_range() {
+		assert!(is_in_srgb_gamut(&Rgb::new(0.0, 0.5, 1.0)));
+		assert!(!is_in_srgb_gamut(&Rgb::new(-0.1, 0.5, 1.0)));
+		assert!(!is_in_srgb_gamut(&Rgb::new(0.0, 0.5, 1.1)));
+	}
+
+	#[test]
+	fn clip_to_srgb_gamut_clips_each_channel() {
+		assert_eq!(
+			clip_to_srgb_gamut(&Rgb::new(-0.1, 0.5, 1.1)),
+			Rgb::new(0.0, 0.5, 1.0)
+		);
+	}
+
+	#[test]
+	fn map_to_srgb_gamut_keeps_colors_in_gamut() {
+		let color = Rgb::new(0.2, 0.5, 0.8);
+
+		assert_eq!(map_to_srgb_gamut(&color), color);
+	}
+
+	#[test]
+	fn map_to_srgb_gamut_maps_into_gamut() {
+		let color: Rgb = Lch::new(60.0, 150.0, 140.0).into_color_unclamped();
+		assert!(!is_in_srgb_gamut(&color));
+
+		let mapped = map_to_srgb_gamut(&color);
+
+		assert!(is_in_srgb_gamut(&mapped));
+		let lightness_difference =
+			(Oklch::from_color_unclamped(mapped).l - Oklch::from_color_unclamped(color).l).abs();
+		assert!(lightness_difference < 0.05);
+	}
+}
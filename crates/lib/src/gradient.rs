@@ -0,0 +1,327 @@
+use palette::rgb::Rgb;
+use palette::{Clamp, FromColorUnclamped, Hsl, Lab, LinSrgb, Oklab, Oklch};
+
+use crate::util::{from_linear_srgb, to_linear_srgb};
+
+/// Chroma (or saturation for HSL) below which the hue of a color is considered missing.
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+
+/// Color space to interpolate colors in, see [`interpolate`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum InterpolationColorSpace {
+	Srgb,
+	LinearSrgb,
+	Lab,
+	Oklab,
+	Oklch,
+	Hsl,
+}
+
+/// How hues are interpolated in color spaces with a hue, i.e. Oklch and HSL.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#hue-interpolation).
+#[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum HueInterpolationMethod {
+	/// Takes the shorter arc between the hues.
+	#[default]
+	Shorter,
+
+	/// Takes the longer arc between the hues.
+	Longer,
+
+	/// Goes from the first to the second hue with increasing angle.
+	Increasing,
+
+	/// Goes from the first to the second hue with decreasing angle.
+	Decreasing,
+}
+
+/// Gets the indices of the hue and of the chroma (or saturation) in the components of the color space,
+/// if it has a hue.
+fn polar_indices(space: InterpolationColorSpace) -> Option<(usize, usize)> {
+	match space {
+		InterpolationColorSpace::Oklch => Some((2, 1)),
+		InterpolationColorSpace::Hsl => Some((0, 1)),
+		_ => None,
+	}
+}
+
+/// Gets the index of the hue in the components of the color space, if it has a hue.
+pub(crate) fn hue_index(space: InterpolationColorSpace) -> Option<usize> {
+	polar_indices(space).map(|(hue_index, _)| hue_index)
+}
+
+/// Converts the color to the components of the color space, with hues in degrees.
+fn to_components(color: &Rgb, space: InterpolationColorSpace) -> [f32; 3] {
+	match space {
+		InterpolationColorSpace::Srgb => [color.red, color.green, color.blue],
+		InterpolationColorSpace::LinearSrgb => {
+			let linear = to_linear_srgb(color);
+			[linear.red, linear.green, linear.blue]
+		}
+		InterpolationColorSpace::Lab => {
+			let lab = Lab::from_color_unclamped(*color);
+			[lab.l, lab.a, lab.b]
+		}
+		InterpolationColorSpace::Oklab => {
+			let oklab = Oklab::from_color_unclamped(*color);
+			[oklab.l, oklab.a, oklab.b]
+		}
+		InterpolationColorSpace::Oklch => {
+			let oklch = Oklch::from_color_unclamped(*color);
+			[oklch.l, oklch.chroma, oklch.hue.into_positive_degrees()]
+		}
+		InterpolationColorSpace::Hsl => {
+			let hsl = Hsl::from_color_unclamped(*color);
+			[
+				hsl.hue.into_positive_degrees(),
+				hsl.saturation,
+				hsl.lightness,
+			]
+		}
+	}
+}
+
+/// Converts the components of the color space back to a color, without clamping it to the sRGB gamut.
+pub(crate) fn from_components(components: [f32; 3], space: InterpolationColorSpace) -> Rgb {
+	let [c1, c2, c3] = components;
+	match space {
+		InterpolationColorSpace::Srgb => Rgb::new(c1, c2, c3),
+		InterpolationColorSpace::LinearSrgb => from_linear_srgb(&LinSrgb::new(c1, c2, c3)),
+		InterpolationColorSpace::Lab => Rgb::from_color_unclamped(Lab::new(c1, c2, c3)),
+		InterpolationColorSpace::Oklab => Rgb::from_color_unclamped(Oklab::new(c1, c2, c3)),
+		InterpolationColorSpace::Oklch => Rgb::from_color_unclamped(Oklch::new(c1, c2, c3)),
+		InterpolationColorSpace::Hsl => Rgb::from_color_unclamped(Hsl::new(c1, c2, c3)),
+	}
+}
+
+/// Adjusts two hues in degrees in the range [0, 360) so that interpolating them linearly
+/// follows the hue interpolation method.
+fn fixup_hues(hue_1: f32, hue_2: f32, method: HueInterpolationMethod) -> (f32, f32) {
+	let difference = hue_2 - hue_1;
+	match method {
+		HueInterpolationMethod::Shorter if difference > 180.0 => (hue_1 + 360.0, hue_2),
+		HueInterpolationMethod::Shorter if difference < -180.0 => (hue_1, hue_2 + 360.0),
+		HueInterpolationMethod::Longer if 0.0 < difference && difference < 180.0 => {
+			(hue_1 + 360.0, hue_2)
+		}
+		HueInterpolationMethod::Longer if -180.0 < difference && difference <= 0.0 => {
+			(hue_1, hue_2 + 360.0)
+		}
+		HueInterpolationMethod::Increasing if difference < 0.0 => (hue_1, hue_2 + 360.0),
+		HueInterpolationMethod::Decreasing if difference > 0.0 => (hue_1 + 360.0, hue_2),
+		_ => (hue_1, hue_2),
+	}
+}
+
+/// Converts both colors to the components of the color space, ready to be interpolated with [`mix_components`].
+/// If either color is achromatic, the hue of the other color is used, like for a missing hue in CSS.
+/// Hues are then adjusted to follow the hue interpolation method.
+pub(crate) fn to_component_pair(
+	start: &Rgb,
+	end: &Rgb,
+	space: InterpolationColorSpace,
+	hue_interpolation: HueInterpolationMethod,
+) -> ([f32; 3], [f32; 3]) {
+	let mut start_components = to_components(start, space);
+	let mut end_components = to_components(end, space);
+	if let Some((hue_index, chroma_index)) = polar_indices(space) {
+		if start_components[chroma_index] < ACHROMATIC_CHROMA {
+			start_components[hue_index] = end_components[hue_index];
+		} else if end_components[chroma_index] < ACHROMATIC_CHROMA {
+			end_components[hue_index] = start_components[hue_index];
+		}
+		let (start_hue, end_hue) = fixup_hues(
+			start_components[hue_index],
+			end_components[hue_index],
+			hue_interpolation,
+		);
+		start_components[hue_index] = start_hue;
+		end_components[hue_index] = end_hue;
+	}
+	(start_components, end_components)
+}
+
+/// Linearly interpolates between the components from [`to_component_pair`], with `t` being the ratio of `end`.
+/// The hue of the result is normalized to the range [0, 360).
+pub(crate) fn mix_components(
+	start: [f32; 3],
+	end: [f32; 3],
+	t: f32,
+	space: InterpolationColorSpace,
+) -> [f32; 3] {
+	let mut components = [0.0; 3];
+	for (i, component) in components.iter_mut().enumerate() {
+		*component = start[i] + (end[i] - start[i]) * t;
+	}
+	if let Some(hue_index) = hue_index(space) {
+		components[hue_index] = components[hue_index].rem_euclid(360.0);
+	}
+	components
+}
+
+/// Interpolates between `start` and `end` in the given color space, with `t` being the ratio of `end` in the result.
+/// `t` is clamped to the range 0 to 1.
+/// Hues of Oklch and HSL are interpolated along the shorter arc. If either color is achromatic,
+/// the hue of the other color is used.
+/// Colors out of the sRGB gamut, e.g. from Lab, are clamped.
+/// For mixing colors with an alpha channel or other hue interpolation methods, see [`crate::blend::color_mix`].
+pub fn interpolate(start: &Rgb, end: &Rgb, t: f32, space: InterpolationColorSpace) -> Rgb {
+	let (start_components, end_components) =
+		to_component_pair(start, end, space, HueInterpolationMethod::Shorter);
+	let components = mix_components(start_components, end_components, t.clamp(0.0, 1.0), space);
+	from_components(components, space).clamp()
+}
+
+/// Creates `steps` colors at evenly spaced positions from 0 to 1 using `interpolate`,
+/// keeping `start` and `end` unchanged to avoid rounding errors from converting them.
+fn gradient<F>(start: &Rgb, end: &Rgb, steps: usize, interpolate: F) -> Vec<Rgb>
+where
+	F: Fn(f32) -> Rgb,
+{
+	assert!(steps > 0, "Gradient must have at least one step.");
+	(0..steps)
+		.map(|i| {
+			if i == 0 {
+				*start
+			} else if i == steps - 1 {
+				*end
+			} else {
+				interpolate(i as f32 / (steps - 1) as f32)
+			}
+		})
+		.collect()
+}
+
+/// Creates `steps` evenly spaced colors from `start` to `end`, interpolating the gamma-compressed sRGB values.
+/// This matches how browsers render CSS gradients by default but may produce a dark band between saturated colors.
+/// Two steps return just `start` and `end`, one step returns just `start`.
+///
+/// # Panics
+///
+/// Panics if `steps` is 0.
+pub fn linear_gradient_srgb(start: &Rgb, end: &Rgb, steps: usize) -> Vec<Rgb> {
+	gradient(start, end, steps, |t| {
+		interpolate(start, end, t, InterpolationColorSpace::Srgb)
+	})
+}
+
+/// Creates `steps` evenly spaced colors from `start` to `end`, interpolating in linear light.
+/// Two steps return just `start` and `end`, one step returns just `start`.
+///
+/// # Panics
+///
+/// Panics if `steps` is 0.
+pub fn linear_gradient_linear(start: &Rgb, end: &Rgb, steps: usize) -> Vec<Rgb> {
+	gradient(start, end, steps, |t| {
+		interpolate(start, end, t, InterpolationColorSpace::LinearSrgb)
+	})
+}
+
+/// Creates `steps` evenly spaced colors from `start` to `end`, interpolating in Oklch along the shorter hue arc.
+/// This keeps the lightness perceptually even and avoids desaturated middle colors.
+/// If either color is achromatic, the hue of the other color is used, like for a missing hue in CSS.
+/// Colors out of the sRGB gamut are clamped.
+/// Two steps return just `start` and `end`, one step returns just `start`.
+///
+/// # Panics
+///
+/// Panics if `steps` is 0.
+pub fn linear_gradient_oklch(start: &Rgb, end: &Rgb, steps: usize) -> Vec<Rgb> {
+	gradient(start, end, steps, |t| {
+		interpolate(start, end, t, InterpolationColorSpace::Oklch)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use palette::FromColor;
+
+	use super::*;
+
+	const RED: Rgb = Rgb::new(1.0, 0.0, 0.0);
+	const BLUE: Rgb = Rgb::new(0.0, 0.0, 1.0);
+
+	#[test]
+	fn linear_gradient_srgb_interpolates() {
+		let gradient = linear_gradient_srgb(&RED, &BLUE, 3);
+
+		assert_eq!(gradient, vec![RED, Rgb::new(0.5, 0.0, 0.5), BLUE]);
+	}
+
+	#[test]
+	fn linear_gradients_two_steps_return_ends() {
+		assert_eq!(linear_gradient_srgb(&RED, &BLUE, 2), vec![RED, BLUE]);
+		assert_eq!(linear_gradient_linear(&RED, &BLUE, 2), vec![RED, BLUE]);
+		assert_eq!(linear_gradient_oklch(&RED, &BLUE, 2), vec![RED, BLUE]);
+	}
+
+	#[test]
+	fn linear_gradient_linear_is_lighter_than_srgb() {
+		let srgb_middle = linear_gradient_srgb(&RED, &BLUE, 3)[1];
+		let linear_middle = linear_gradient_linear(&RED, &BLUE, 3)[1];
+
+		assert!(linear_middle.red > srgb_middle.red);
+		assert!(linear_middle.blue > srgb_middle.blue);
+	}
+
+	#[test]
+	fn linear_gradient_oklch_keeps_hue_for_achromatic_end() {
+		let color = Rgb::new(0.6, 0.3, 0.3);
+		let white = Rgb::new(1.0, 1.0, 1.0);
+
+		let middle = linear_gradient_oklch(&color, &white, 3)[1];
+
+		let middle_hue = Oklch::from_color(middle).hue.into_degrees();
+		let color_hue = Oklch::from_color(color).hue.into_degrees();
+		assert!((middle_hue - color_hue).abs() < 1.0);
+	}
+
+	#[test]
+	fn interpolate_returns_ends() {
+		for space in [
+			InterpolationColorSpace::Srgb,
+			InterpolationColorSpace::LinearSrgb,
+			InterpolationColorSpace::Lab,
+			InterpolationColorSpace::Oklab,
+			InterpolationColorSpace::Oklch,
+			InterpolationColorSpace::Hsl,
+		] {
+			let result = interpolate(&RED, &BLUE, 1.0, space);
+			assert_eq!(
+				result.into_format::<u8>(),
+				BLUE.into_format::<u8>(),
+				"{:?}",
+				space
+			);
+		}
+	}
+
+	#[test]
+	fn interpolate_matches_gradients() {
+		assert_eq!(
+			interpolate(&RED, &BLUE, 0.5, InterpolationColorSpace::Srgb),
+			linear_gradient_srgb(&RED, &BLUE, 3)[1]
+		);
+		assert_eq!(
+			interpolate(&RED, &BLUE, 0.5, InterpolationColorSpace::Oklch),
+			linear_gradient_oklch(&RED, &BLUE, 3)[1]
+		);
+	}
+
+	#[test]
+	fn interpolate_hsl_uses_shorter_hue_arc() {
+		let magenta = Rgb::new(1.0, 0.0, 1.0);
+		let orange = Rgb::new(1.0, 0.5, 0.0);
+
+		let middle = interpolate(&magenta, &orange, 0.5, InterpolationColorSpace::Hsl);
+
+		let hue = Hsl::from_color(middle).hue.into_positive_degrees();
+		assert!((hue - 345.0).abs() < 1.0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn linear_gradient_srgb_rejects_zero_steps() {
+		linear_gradient_srgb(&RED, &BLUE, 0);
+	}
+}
@@ -1,2 +1,15 @@
+pub mod blend;
+pub mod contrast;
+pub mod display_p3;
+pub mod distance;
+pub mod gamut;
+pub mod gradient;
+pub mod math;
+pub mod named_colors;
+pub mod operations;
+pub mod parser;
+#[cfg(feature = "serde")]
+pub mod serde_hex;
+pub mod simulation;
 pub mod to_str;
 pub mod util;
@@ -0,0 +1,58 @@
+//! Rounding helpers for formatting color values with a fixed number of decimal places.
+//!
+//! Note that values are scaled by a power of ten as binary floating point numbers,
+//! so values that cannot be represented exactly (e.g. `2.675`) may round differently than expected.
+
+fn decimal_factor(n: u8) -> f64 {
+	10_f64.powi(i32::from(n))
+}
+
+/// Rounds the value down to `n` decimal places, e.g. `4.499` to `4.49` for `n = 2`.
+/// Useful for thresholds like contrast ratios, where rounding up could suggest a threshold is met.
+pub fn floor_n_decimals(val: f64, n: u8) -> f64 {
+	let factor = decimal_factor(n);
+	(val * factor).floor() / factor
+}
+
+/// Rounds the value up to `n` decimal places, e.g. `4.491` to `4.5` for `n = 2`.
+///
+/// When rounding `f32` values, note that widening them to `f64` keeps their binary error,
+/// e.g. `0.6_f32` widens to `0.6000000238418579` and is rounded up to `0.61`.
+pub fn ceil_n_decimals(val: f64, n: u8) -> f64 {
+	let factor = decimal_factor(n);
+	(val * factor).ceil() / factor
+}
+
+/// Rounds the value to `n` decimal places, rounding ties to even (banker's rounding),
+/// e.g. `0.125` to `0.12` and `0.375` to `0.38` for `n = 2`.
+pub fn round_n_decimals(val: f64, n: u8) -> f64 {
+	let factor = decimal_factor(n);
+	(val * factor).round_ties_even() / factor
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn floor_n_decimals_rounds_down() {
+		assert_eq!(floor_n_decimals(4.499, 2), 4.49);
+		assert_eq!(floor_n_decimals(-1.25, 1), -1.3);
+		assert_eq!(floor_n_decimals(1.5, 0), 1.0);
+	}
+
+	#[test]
+	fn ceil_n_decimals_rounds_up() {
+		assert_eq!(ceil_n_decimals(4.491, 2), 4.5);
+		assert_eq!(ceil_n_decimals(-1.25, 1), -1.2);
+		assert_eq!(ceil_n_decimals(1.5, 0), 2.0);
+	}
+
+	#[test]
+	fn round_n_decimals_rounds_ties_to_even() {
+		assert_eq!(round_n_decimals(0.125, 2), 0.12);
+		assert_eq!(round_n_decimals(0.375, 2), 0.38);
+		assert_eq!(round_n_decimals(2.5, 0), 2.0);
+		assert_eq!(round_n_decimals(0.126, 2), 0.13);
+	}
+}
@@ -0,0 +1,560 @@
+use std::str::FromStr;
+
+use palette::rgb::Rgb;
+use palette::white_point::D65;
+use palette::{IntoColor, Lab};
+
+use crate::distance::delta_e_ciede2000;
+use crate::parser::ParsingError;
+
+/// Named colors as defined by CSS, from `aliceblue` to `yellowgreen`.
+/// Some colors have multiple names, e.g. `gray` and `grey`.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#named-colors).
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum CssNamedColor {
+	AliceBlue,
+	AntiqueWhite,
+	Aqua,
+	Aquamarine,
+	Azure,
+	Beige,
+	Bisque,
+	Black,
+	BlanchedAlmond,
+	Blue,
+	BlueViolet,
+	Brown,
+	BurlyWood,
+	CadetBlue,
+	Chartreuse,
+	Chocolate,
+	Coral,
+	CornflowerBlue,
+	Cornsilk,
+	Crimson,
+	Cyan,
+	DarkBlue,
+	DarkCyan,
+	DarkGoldenRod,
+	DarkGray,
+	DarkGreen,
+	DarkGrey,
+	DarkKhaki,
+	DarkMagenta,
+	DarkOliveGreen,
+	DarkOrange,
+	DarkOrchid,
+	DarkRed,
+	DarkSalmon,
+	DarkSeaGreen,
+	DarkSlateBlue,
+	DarkSlateGray,
+	DarkSlateGrey,
+	DarkTurquoise,
+	DarkViolet,
+	DeepPink,
+	DeepSkyBlue,
+	DimGray,
+	DimGrey,
+	DodgerBlue,
+	FireBrick,
+	FloralWhite,
+	ForestGreen,
+	Fuchsia,
+	Gainsboro,
+	GhostWhite,
+	Gold,
+	GoldenRod,
+	Gray,
+	Green,
+	GreenYellow,
+	Grey,
+	HoneyDew,
+	HotPink,
+	IndianRed,
+	Indigo,
+	Ivory,
+	Khaki,
+	Lavender,
+	LavenderBlush,
+	LawnGreen,
+	LemonChiffon,
+	LightBlue,
+	LightCoral,
+	LightCyan,
+	LightGoldenRodYellow,
+	LightGray,
+	LightGreen,
+	LightGrey,
+	LightPink,
+	LightSalmon,
+	LightSeaGreen,
+	LightSkyBlue,
+	LightSlateGray,
+	LightSlateGrey,
+	LightSteelBlue,
+	LightYellow,
+	Lime,
+	LimeGreen,
+	Linen,
+	Magenta,
+	Maroon,
+	MediumAquamarine,
+	MediumBlue,
+	MediumOrchid,
+	MediumPurple,
+	MediumSeaGreen,
+	MediumSlateBlue,
+	MediumSpringGreen,
+	MediumTurquoise,
+	MediumVioletRed,
+	MidnightBlue,
+	MintCream,
+	MistyRose,
+	Moccasin,
+	NavajoWhite,
+	Navy,
+	OldLace,
+	Olive,
+	OliveDrab,
+	Orange,
+	OrangeRed,
+	Orchid,
+	PaleGoldenRod,
+	PaleGreen,
+	PaleTurquoise,
+	PaleVioletRed,
+	PapayaWhip,
+	PeachPuff,
+	Peru,
+	Pink,
+	Plum,
+	PowderBlue,
+	Purple,
+	RebeccaPurple,
+	Red,
+	RosyBrown,
+	RoyalBlue,
+	SaddleBrown,
+	Salmon,
+	SandyBrown,
+	SeaGreen,
+	SeaShell,
+	Sienna,
+	Silver,
+	SkyBlue,
+	SlateBlue,
+	SlateGray,
+	SlateGrey,
+	Snow,
+	SpringGreen,
+	SteelBlue,
+	Tan,
+	Teal,
+	Thistle,
+	Tomato,
+	Turquoise,
+	Violet,
+	Wheat,
+	White,
+	WhiteSmoke,
+	Yellow,
+	YellowGreen,
+}
+
+/// Names and channels of the named colors, in the order of the [`CssNamedColor`] variants.
+const NAMED_COLORS: [(&str, [u8; 3]); 148] = [
+	("aliceblue", [240, 248, 255]),
+	("antiquewhite", [250, 235, 215]),
+	("aqua", [0, 255, 255]),
+	("aquamarine", [127, 255, 212]),
+	("azure", [240, 255, 255]),
+	("beige", [245, 245, 220]),
+	("bisque", [255, 228, 196]),
+	("black", [0, 0, 0]),
+	("blanchedalmond", [255, 235, 205]),
+	("blue", [0, 0, 255]),
+	("blueviolet", [138, 43, 226]),
+	("brown", [165, 42, 42]),
+	("burlywood", [222, 184, 135]),
+	("cadetblue", [95, 158, 160]),
+	("chartreuse", [127, 255, 0]),
+	("chocolate", [210, 105, 30]),
+	("coral", [255, 127, 80]),
+	("cornflowerblue", [100, 149, 237]),
+	("cornsilk", [255, 248, 220]),
+	("crimson", [220, 20, 60]),
+	("cyan", [0, 255, 255]),
+	("darkblue", [0, 0, 139]),
+	("darkcyan", [0, 139, 139]),
+	("darkgoldenrod", [184, 134, 11]),
+	("darkgray", [169, 169, 169]),
+	("darkgreen", [0, 100, 0]),
+	("darkgrey", [169, 169, 169]),
+	("darkkhaki", [189, 183, 107]),
+	("darkmagenta", [139, 0, 139]),
+	("darkolivegreen", [85, 107, 47]),
+	("darkorange", [255, 140, 0]),
+	("darkorchid", [153, 50, 204]),
+	("darkred", [139, 0, 0]),
+	("darksalmon", [233, 150, 122]),
+	("darkseagreen", [143, 188, 143]),
+	("darkslateblue", [72, 61, 139]),
+	("darkslategray", [47, 79, 79]),
+	("darkslategrey", [47, 79, 79]),
+	("darkturquoise", [0, 206, 209]),
+	("darkviolet", [148, 0, 211]),
+	("deeppink", [255, 20, 147]),
+	("deepskyblue", [0, 191, 255]),
+	("dimgray", [105, 105, 105]),
+	("dimgrey", [105, 105, 105]),
+	("dodgerblue", [30, 144, 255]),
+	("firebrick", [178, 34, 34]),
+	("floralwhite", [255, 250, 240]),
+	("forestgreen", [34, 139, 34]),
+	("fuchsia", [255, 0, 255]),
+	("gainsboro", [220, 220, 220]),
+	("ghostwhite", [248, 248, 255]),
+	("gold", [255, 215, 0]),
+	("goldenrod", [218, 165, 32]),
+	("gray", [128, 128, 128]),
+	("green", [0, 128, 0]),
+	("greenyellow", [173, 255, 47]),
+	("grey", [128, 128, 128]),
+	("honeydew", [240, 255, 240]),
+	("hotpink", [255, 105, 180]),
+	("indianred", [205, 92, 92]),
+	("indigo", [75, 0, 130]),
+	("ivory", [255, 255, 240]),
+	("khaki", [240, 230, 140]),
+	("lavender", [230, 230, 250]),
+	("lavenderblush", [255, 240, 245]),
+	("lawngreen", [124, 252, 0]),
+	("lemonchiffon", [255, 250, 205]),
+	("lightblue", [173, 216, 230]),
+	("lightcoral", [240, 128, 128]),
+	("lightcyan", [224, 255, 255]),
+	("lightgoldenrodyellow", [250, 250, 210]),
+	("lightgray", [211, 211, 211]),
+	("lightgreen", [144, 238, 144]),
+	("lightgrey", [211, 211, 211]),
+	("lightpink", [255, 182, 193]),
+	("lightsalmon", [255, 160, 122]),
+	("lightseagreen", [32, 178, 170]),
+	("lightskyblue", [135, 206, 250]),
+	("lightslategray", [119, 136, 153]),
+	("lightslategrey", [119, 136, 153]),
+	("lightsteelblue", [176, 196, 222]),
+	("lightyellow", [255, 255, 224]),
+	("lime", [0, 255, 0]),
+	("limegreen", [50, 205, 50]),
+	("linen", [250, 240, 230]),
+	("magenta", [255, 0, 255]),
+	("maroon", [128, 0, 0]),
+	("mediumaquamarine", [102, 205, 170]),
+	("mediumblue", [0, 0, 205]),
+	("mediumorchid", [186, 85, 211]),
+	("mediumpurple", [147, 112, 219]),
+	("mediumseagreen", [60, 179, 113]),
+	("mediumslateblue", [123, 104, 238]),
+	("mediumspringgreen", [0, 250, 154]),
+	("mediumturquoise", [72, 209, 204]),
+	("mediumvioletred", [199, 21, 133]),
+	("midnightblue", [25, 25, 112]),
+	("mintcream", [245, 255, 250]),
+	("mistyrose", [255, 228, 225]),
+	("moccasin", [255, 228, 181]),
+	("navajowhite", [255, 222, 173]),
+	("navy", [0, 0, 128]),
+	("oldlace", [253, 245, 230]),
+	("olive", [128, 128, 0]),
+	("olivedrab", [107, 142, 35]),
+	("orange", [255, 165, 0]),
+	("orangered", [255, 69, 0]),
+	("orchid", [218, 112, 214]),
+	("palegoldenrod", [238, 232, 170]),
+	("palegreen", [152, 251, 152]),
+	("paleturquoise", [175, 238, 238]),
+	("palevioletred", [219, 112, 147]),
+	("papayawhip", [255, 239, 213]),
+	("peachpuff", [255, 218, 185]),
+	("peru", [205, 133, 63]),
+	("pink", [255, 192, 203]),
+	("plum", [221, 160, 221]),
+	("powderblue", [176, 224, 230]),
+	("purple", [128, 0, 128]),
+	("rebeccapurple", [102, 51, 153]),
+	("red", [255, 0, 0]),
+	("rosybrown", [188, 143, 143]),
+	("royalblue", [65, 105, 225]),
+	("saddlebrown", [139, 69, 19]),
+	("salmon", [250, 128, 114]),
+	("sandybrown", [244, 164, 96]),
+	("seagreen", [46, 139, 87]),
+	("seashell", [255, 245, 238]),
+	("sienna", [160, 82, 45]),
+	("silver", [192, 192, 192]),
+	("skyblue", [135, 206, 235]),
+	("slateblue", [106, 90, 205]),
+	("slategray", [112, 128, 144]),
+	("slategrey", [112, 128, 144]),
+	("snow", [255, 250, 250]),
+	("springgreen", [0, 255, 127]),
+	("steelblue", [70, 130, 180]),
+	("tan", [210, 180, 140]),
+	("teal", [0, 128, 128]),
+	("thistle", [216, 191, 216]),
+	("tomato", [255, 99, 71]),
+	("turquoise", [64, 224, 208]),
+	("violet", [238, 130, 238]),
+	("wheat", [245, 222, 179]),
+	("white", [255, 255, 255]),
+	("whitesmoke", [245, 245, 245]),
+	("yellow", [255, 255, 0]),
+	("yellowgreen", [154, 205, 50]),
+];
+
+impl CssNamedColor {
+	/// All named colors in alphabetical order.
+	pub const ALL: [CssNamedColor; 148] = [
+		CssNamedColor::AliceBlue,
+		CssNamedColor::AntiqueWhite,
+		CssNamedColor::Aqua,
+		CssNamedColor::Aquamarine,
+		CssNamedColor::Azure,
+		CssNamedColor::Beige,
+		CssNamedColor::Bisque,
+		CssNamedColor::Black,
+		CssNamedColor::BlanchedAlmond,
+		CssNamedColor::Blue,
+		CssNamedColor::BlueViolet,
+		CssNamedColor::Brown,
+		CssNamedColor::BurlyWood,
+		CssNamedColor::CadetBlue,
+		CssNamedColor::Chartreuse,
+		CssNamedColor::Chocolate,
+		CssNamedColor::Coral,
+		CssNamedColor::CornflowerBlue,
+		CssNamedColor::Cornsilk,
+		CssNamedColor::Crimson,
+		CssNamedColor::Cyan,
+		CssNamedColor::DarkBlue,
+		CssNamedColor::DarkCyan,
+		CssNamedColor::DarkGoldenRod,
+		CssNamedColor::DarkGray,
+		CssNamedColor::DarkGreen,
+		CssNamedColor::DarkGrey,
+		CssNamedColor::DarkKhaki,
+		CssNamedColor::DarkMagenta,
+		CssNamedColor::DarkOliveGreen,
+		CssNamedColor::DarkOrange,
+		CssNamedColor::DarkOrchid,
+		CssNamedColor::DarkRed,
+		CssNamedColor::DarkSalmon,
+		CssNamedColor::DarkSeaGreen,
+		CssNamedColor::DarkSlateBlue,
+		CssNamedColor::DarkSlateGray,
+		CssNamedColor::DarkSlateGrey,
+		CssNamedColor::DarkTurquoise,
+		CssNamedColor::DarkViolet,
+		CssNamedColor::DeepPink,
+		CssNamedColor::DeepSkyBlue,
+		CssNamedColor::DimGray,
+		CssNamedColor::DimGrey,
+		CssNamedColor::DodgerBlue,
+		CssNamedColor::FireBrick,
+		CssNamedColor::FloralWhite,
+		CssNamedColor::ForestGreen,
+		CssNamedColor::Fuchsia,
+		CssNamedColor::Gainsboro,
+		CssNamedColor::GhostWhite,
+		CssNamedColor::Gold,
+		CssNamedColor::GoldenRod,
+		CssNamedColor::Gray,
+		CssNamedColor::Green,
+		CssNamedColor::GreenYellow,
+		CssNamedColor::Grey,
+		CssNamedColor::HoneyDew,
+		CssNamedColor::HotPink,
+		CssNamedColor::IndianRed,
+		CssNamedColor::Indigo,
+		CssNamedColor::Ivory,
+		CssNamedColor::Khaki,
+		CssNamedColor::Lavender,
+		CssNamedColor::LavenderBlush,
+		CssNamedColor::LawnGreen,
+		CssNamedColor::LemonChiffon,
+		CssNamedColor::LightBlue,
+		CssNamedColor::LightCoral,
+		CssNamedColor::LightCyan,
+		CssNamedColor::LightGoldenRodYellow,
+		CssNamedColor::LightGray,
+		CssNamedColor::LightGreen,
+		CssNamedColor::LightGrey,
+		CssNamedColor::LightPink,
+		CssNamedColor::LightSalmon,
+		CssNamedColor::LightSeaGreen,
+		CssNamedColor::LightSkyBlue,
+		CssNamedColor::LightSlateGray,
+		CssNamedColor::LightSlateGrey,
+		CssNamedColor::LightSteelBlue,
+		CssNamedColor::LightYellow,
+		CssNamedColor::Lime,
+		CssNamedColor::LimeGreen,
+		CssNamedColor::Linen,
+		CssNamedColor::Magenta,
+		CssNamedColor::Maroon,
+		CssNamedColor::MediumAquamarine,
+		CssNamedColor::MediumBlue,
+		CssNamedColor::MediumOrchid,
+		CssNamedColor::MediumPurple,
+		CssNamedColor::MediumSeaGreen,
+		CssNamedColor::MediumSlateBlue,
+		CssNamedColor::MediumSpringGreen,
+		CssNamedColor::MediumTurquoise,
+		CssNamedColor::MediumVioletRed,
+		CssNamedColor::MidnightBlue,
+		CssNamedColor::MintCream,
+		CssNamedColor::MistyRose,
+		CssNamedColor::Moccasin,
+		CssNamedColor::NavajoWhite,
+		CssNamedColor::Navy,
+		CssNamedColor::OldLace,
+		CssNamedColor::Olive,
+		CssNamedColor::OliveDrab,
+		CssNamedColor::Orange,
+		CssNamedColor::OrangeRed,
+		CssNamedColor::Orchid,
+		CssNamedColor::PaleGoldenRod,
+		CssNamedColor::PaleGreen,
+		CssNamedColor::PaleTurquoise,
+		CssNamedColor::PaleVioletRed,
+		CssNamedColor::PapayaWhip,
+		CssNamedColor::PeachPuff,
+		CssNamedColor::Peru,
+		CssNamedColor::Pink,
+		CssNamedColor::Plum,
+		CssNamedColor::PowderBlue,
+		CssNamedColor::Purple,
+		CssNamedColor::RebeccaPurple,
+		CssNamedColor::Red,
+		CssNamedColor::RosyBrown,
+		CssNamedColor::RoyalBlue,
+		CssNamedColor::SaddleBrown,
+		CssNamedColor::Salmon,
+		CssNamedColor::SandyBrown,
+		CssNamedColor::SeaGreen,
+		CssNamedColor::SeaShell,
+		CssNamedColor::Sienna,
+		CssNamedColor::Silver,
+		CssNamedColor::SkyBlue,
+		CssNamedColor::SlateBlue,
+		CssNamedColor::SlateGray,
+		CssNamedColor::SlateGrey,
+		CssNamedColor::Snow,
+		CssNamedColor::SpringGreen,
+		CssNamedColor::SteelBlue,
+		CssNamedColor::Tan,
+		CssNamedColor::Teal,
+		CssNamedColor::Thistle,
+		CssNamedColor::Tomato,
+		CssNamedColor::Turquoise,
+		CssNamedColor::Violet,
+		CssNamedColor::Wheat,
+		CssNamedColor::White,
+		CssNamedColor::WhiteSmoke,
+		CssNamedColor::Yellow,
+		CssNamedColor::YellowGreen,
+	];
+
+	/// Gets the lowercase CSS name of the color, e.g. `aliceblue`.
+	pub fn name(&self) -> &'static str {
+		NAMED_COLORS[*self as usize].0
+	}
+
+	/// Gets the exact sRGB value of the color.
+	pub fn to_rgb(&self) -> Rgb {
+		let [red, green, blue] = NAMED_COLORS[*self as usize].1;
+		Rgb::<_, u8>::new(red, green, blue).into_format()
+	}
+}
+
+impl FromStr for CssNamedColor {
+	type Err = ParsingError;
+
+	/// Looks up a named color by its name, ignoring case.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		CssNamedColor::ALL
+			.into_iter()
+			.find(|named_color| named_color.name().eq_ignore_ascii_case(s))
+			.ok_or_else(|| {
+				ParsingError::InvalidSyntax(format!("'{s}' is not a named color.").into())
+			})
+	}
+}
+
+/// Finds the named color that is perceptually closest to the color using CIEDE2000.
+/// If multiple names share the closest value, the first one in alphabetical order is used.
+pub fn nearest_named_color(color: &Rgb) -> CssNamedColor {
+	let color_lab: Lab<D65, f32> = (*color).into_color();
+	CssNamedColor::ALL
+		.into_iter()
+		.map(|named_color| {
+			let named_color_lab: Lab<D65, f32> = named_color.to_rgb().into_color();
+			(named_color, delta_e_ciede2000(&color_lab, &named_color_lab))
+		})
+		.min_by(|(_, x), (_, y)| x.total_cmp(y))
+		.map(|(named_color, _)| named_color)
+		.expect("There is at least one named color.")
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::parser::parse_color;
+
+	use super::*;
+
+	#[test]
+	fn css_named_color_name_and_to_rgb() {
+		assert_eq!(CssNamedColor::RebeccaPurple.name(), "rebeccapurple");
+		assert_eq!(
+			CssNamedColor::RebeccaPurple.to_rgb().into_format::<u8>(),
+			Rgb::<_, u8>::new(102, 51, 153)
+		);
+	}
+
+	#[test]
+	fn css_named_color_matches_parser() {
+		for named_color in CssNamedColor::ALL {
+			assert_eq!(
+				parse_color(named_color.name()).unwrap().without_alpha(),
+				named_color.to_rgb(),
+				"{}",
+				named_color.name()
+			);
+		}
+	}
+
+	#[test]
+	fn css_named_color_from_str_ignores_case() {
+		assert_eq!("AliceBlue".parse(), Ok(CssNamedColor::AliceBlue));
+		assert_eq!("grey".parse(), Ok(CssNamedColor::Grey));
+		assert!("transparent".parse::<CssNamedColor>().is_err());
+	}
+
+	#[test]
+	fn nearest_named_color_finds_closest() {
+		assert_eq!(
+			nearest_named_color(&Rgb::new(0.0, 0.0, 0.0)),
+			CssNamedColor::Black
+		);
+		assert_eq!(
+			nearest_named_color(&Rgb::<_, u8>::new(250, 2, 3).into_format()),
+			CssNamedColor::Red
+		);
+		assert_eq!(
+			nearest_named_color(&Rgb::<_, u8>::new(128, 128, 128).into_format()),
+			CssNamedColor::Gray
+		);
+	}
+}
@@ -0,0 +1,783 @@
+use palette::blend::Compose;
+use palette::rgb::Rgb;
+use palette::{FromColor, Hsl, Hsla, IntoColor, LinSrgba, Mix, Oklaba, ShiftHue, Srgba};
+
+use crate::util::{is_achromatic, BLACK, WHITE};
+
+/// Converts HSL components to HWB components without going through RGB.
+/// Hue is in degrees and passed through unchanged, all other components are in the range 0 to 1.
+pub fn hsl_to_hwb(hue: f32, saturation: f32, lightness: f32) -> (f32, f32, f32) {
+	// HSV value and saturation are an intermediate step.
+	let value = lightness + saturation * lightness.min(1.0 - lightness);
+	let value_saturation = if value == 0.0 {
+		0.0
+	} else {
+		2.0 * (1.0 - lightness / value)
+	};
+
+	let whiteness = (1.0 - value_saturation) * value;
+	let blackness = 1.0 - value;
+	(hue, whiteness, blackness)
+}
+
+/// Converts HWB components to HSL components without going through RGB.
+/// Hue is in degrees and passed through unchanged, all other components are in the range 0 to 1.
+pub fn hwb_to_hsl(hue: f32, whiteness: f32, blackness: f32) -> (f32, f32, f32) {
+	if whiteness + blackness >= 1.0 {
+		// Achromatic, see <https://www.w3.org/TR/css-color-4/#hwb-to-rgb>.
+		let gray = whiteness / (whiteness + blackness);
+		return (hue, 0.0, gray);
+	}
+
+	// HSV value and saturation are an intermediate step.
+	let value = 1.0 - blackness;
+	let value_saturation = if value == 0.0 {
+		0.0
+	} else {
+		1.0 - whiteness / value
+	};
+
+	let lightness = value * (1.0 - value_saturation / 2.0);
+	let saturation = if lightness == 0.0 || lightness == 1.0 {
+		0.0
+	} else {
+		(value - lightness) / lightness.min(1.0 - lightness)
+	};
+	(hue, saturation, lightness)
+}
+
+/// Mixes two colors, with `t` being the ratio of `b` in the result.
+/// The colors are mixed in linear light, avoiding the dark band that appears when mixing
+/// gamma-compressed sRGB values, e.g. between red and green.
+/// `t` is clamped to the range 0 to 1.
+pub fn mix_with_gamma_correction(a: &Srgba, b: &Srgba, t: f32) -> Srgba {
+	let a_linear = a.into_linear::<f32, f32>();
+	let b_linear = b.into_linear::<f32, f32>();
+	Srgba::from_linear(a_linear.mix(b_linear, t))
+}
+
+/// Mixes two colors, with `t` being the ratio of `b` in the result.
+/// The colors are mixed in Oklab, which is perceptually uniform.
+/// `t` is clamped to the range 0 to 1, a ratio of 0 or 1 returns the respective color unchanged.
+pub fn mix_in_oklab(a: &Srgba, b: &Srgba, t: f32) -> Srgba {
+	if t <= 0.0 {
+		return *a;
+	}
+	if t >= 1.0 {
+		return *b;
+	}
+	let a_oklab: Oklaba = (*a).into_color();
+	let b_oklab: Oklaba = (*b).into_color();
+	Srgba::from_color(a_oklab.mix(b_oklab, t))
+}
+
+/// Linearly interpolates each channel including alpha independently,
+/// computing `a * (1 - t) + b * t` on the gamma-compressed values.
+/// Unlike the mix functions, `t` may be outside of the range 0 to 1 to extrapolate,
+/// each resulting channel is clamped to the range 0 to 1.
+/// Useful for premultiplied alpha, for regular colors prefer [`mix_in_oklab`].
+pub fn lerp(a: &Srgba, b: &Srgba, t: f32) -> Srgba {
+	let lerp_channel = |a: f32, b: f32| (a * (1.0 - t) + b * t).clamp(0.0, 1.0);
+	Srgba::new(
+		lerp_channel(a.red, b.red),
+		lerp_channel(a.green, b.green),
+		lerp_channel(a.blue, b.blue),
+		lerp_channel(a.alpha, b.alpha),
+	)
+}
+
+/// Composites `src` over `bg` using the Porter-Duff "over" operation in linear light.
+pub fn alpha_composite_over(src: &Srgba, bg: &Srgba) -> Srgba {
+	let src_linear = src.into_linear::<f32, f32>();
+	let bg_linear = bg.into_linear::<f32, f32>();
+	Srgba::from_linear(src_linear.over(bg_linear))
+}
+
+/// Multiplies each color channel by alpha in linear light, keeping alpha itself.
+/// The result is still encoded as sRGB, so that it can be stored like a regular color.
+///
+/// Note that [`unpremultiply_alpha`] does not exactly restore the original color due to floating point rounding,
+/// and all color information is lost for fully transparent colors.
+pub fn premultiply_alpha(color: &Srgba) -> Srgba {
+	let linear = color.into_linear::<f32, f32>();
+	Srgba::from_linear(LinSrgba::new(
+		linear.red * linear.alpha,
+		linear.green * linear.alpha,
+		linear.blue * linear.alpha,
+		linear.alpha,
+	))
+}
+
+/// Divides each color channel by alpha in linear light, reversing [`premultiply_alpha`].
+/// Fully transparent colors result in transparent black, channels are clamped to the range 0 to 1.
+pub fn unpremultiply_alpha(color: &Srgba) -> Srgba {
+	if color.alpha == 0.0 {
+		return Srgba::new(0.0, 0.0, 0.0, 0.0);
+	}
+	let linear = color.into_linear::<f32, f32>();
+	let unpremultiply_channel = |channel: f32| (channel / linear.alpha).clamp(0.0, 1.0);
+	Srgba::from_linear(LinSrgba::new(
+		unpremultiply_channel(linear.red),
+		unpremultiply_channel(linear.green),
+		unpremultiply_channel(linear.blue),
+		linear.alpha,
+	))
+}
+
+/// Rotates the HSL hue of the color by the given degrees, preserving saturation and lightness.
+/// Rotating by 180 degrees results in the complementary color.
+pub fn rotate_hue(color: &Srgba, degrees: f32) -> Srgba {
+	let hsla: Hsla = (*color).into_color();
+	Srgba::from_color(hsla.shift_hue(degrees))
+}
+
+/// Increases the HSL lightness of the color by the given amount, clamped to the range 0 to 1.
+pub fn lighten(color: &Rgb, amount: f32) -> Rgb {
+	let mut hsl: Hsl = (*color).into_color();
+	hsl.lightness = (hsl.lightness + amount).clamp(0.0, 1.0);
+	Rgb::from_color(hsl)
+}
+
+/// Decreases the HSL lightness of the color by the given amount, clamped to the range 0 to 1.
+pub fn darken(color: &Rgb, amount: f32) -> Rgb {
+	lighten(color, -amount)
+}
+
+/// Increases the HSL saturation of the color by the given amount, clamped to the range 0 to 1.
+pub fn saturate(color: &Rgb, amount: f32) -> Rgb {
+	let mut hsl: Hsl = (*color).into_color();
+	hsl.saturation = (hsl.saturation + amount).clamp(0.0, 1.0);
+	Rgb::from_color(hsl)
+}
+
+/// Decreases the HSL saturation of the color by the given amount, clamped to the range 0 to 1.
+pub fn desaturate(color: &Rgb, amount: f32) -> Rgb {
+	saturate(color, -amount)
+}
+
+/// `#808080`.
+const GRAY: Rgb = Rgb::new(128.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0);
+
+/// Mixes the color with white, with `amount` being the ratio of white in the result.
+/// The amount is clamped to the range 0 to 1.
+pub fn tint(color: &Rgb, amount: f32) -> Rgb {
+	color.mix(WHITE.color, amount)
+}
+
+/// Mixes the color with black, with `amount` being the ratio of black in the result.
+/// The amount is clamped to the range 0 to 1.
+pub fn shade(color: &Rgb, amount: f32) -> Rgb {
+	color.mix(BLACK.color, amount)
+}
+
+/// Mixes the color with `#808080`, with `amount` being the ratio of gray in the result.
+/// The amount is clamped to the range 0 to 1.
+pub fn tone(color: &Rgb, amount: f32) -> Rgb {
+	color.mix(GRAY, amount)
+}
+
+/// Inverts each color channel, preserving alpha.
+pub fn invert(color: &Srgba) -> Srgba {
+	Srgba::new(
+		1.0 - color.red,
+		1.0 - color.green,
+		1.0 - color.blue,
+		color.alpha,
+	)
+}
+
+/// Number of steps between the web-safe channel values `0x00`, `0x33`, `0x66`, `0x99`, `0xCC` and `0xFF`.
+const WEB_SAFE_STEPS: f32 = 5.0;
+
+/// Maximum difference to a web-safe channel value for floating point errors.
+const WEB_SAFE_TOLERANCE: f32 = 1e-4;
+
+/// Rounds each channel to the nearest web-safe value, i.e. a multiple of `0x33`,
+/// resulting in one of the 216 web-safe colors. Channels out of range are clamped.
+pub fn to_web_safe(color: &Rgb) -> Rgb {
+	let web_safe_channel =
+		|channel: f32| (channel.clamp(0.0, 1.0) * WEB_SAFE_STEPS).round() / WEB_SAFE_STEPS;
+	Rgb::new(
+		web_safe_channel(color.red),
+		web_safe_channel(color.green),
+		web_safe_channel(color.blue),
+	)
+}
+
+/// Checks if all channels are web-safe values, see [`to_web_safe`].
+pub fn is_web_safe(color: &Rgb) -> bool {
+	[color.red, color.green, color.blue].iter().all(|channel| {
+		(0.0..=1.0).contains(channel)
+			&& ((channel * WEB_SAFE_STEPS).round() - channel * WEB_SAFE_STEPS).abs()
+				<= WEB_SAFE_TOLERANCE
+	})
+}
+
+/// Converts the color to a gray with the same relative luminance, preserving alpha.
+/// See <https://www.w3.org/TR/WCAG21/#dfn-relative-luminance>.
+pub fn to_grayscale(color: &Srgba) -> Srgba {
+	let linear = color.into_linear::<f32, f32>();
+	let luminance = 0.2126 * linear.red + 0.7152 * linear.green + 0.0722 * linear.blue;
+	Srgba::from_linear(LinSrgba::new(luminance, luminance, luminance, linear.alpha))
+}
+
+/// Sector of the color wheel a hue belongs to.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ColorSector {
+	Red,
+	Orange,
+	Yellow,
+	YellowGreen,
+	Green,
+	Cyan,
+	Blue,
+	Violet,
+	Magenta,
+	Pink,
+}
+
+/// Gets the HSL hue of the color in degrees in the range [0, 360).
+/// Returns `None` if the color is (nearly) achromatic, i.e. its saturation is 10% or less.
+pub fn color_wheel_position(color: &Rgb) -> Option<f32> {
+	let hsl: Hsl = (*color).into_color();
+	if hsl.saturation > 0.1 {
+		Some(hsl.hue.into_positive_degrees())
+	} else {
+		None
+	}
+}
+
+/// Gets the sector of the color wheel the color belongs to.
+/// Sectors are 30 degrees wide and centered on their color, except for green and blue,
+/// which span 60 degrees each.
+/// Returns `None` if the color is (nearly) achromatic, see [`color_wheel_position`].
+pub fn color_wheel_sector(color: &Rgb) -> Option<ColorSector> {
+	color_wheel_position(color).map(|hue| match hue {
+		h if h < 15.0 => ColorSector::Red,
+		h if h < 45.0 => ColorSector::Orange,
+		h if h < 75.0 => ColorSector::Yellow,
+		h if h < 105.0 => ColorSector::YellowGreen,
+		h if h < 165.0 => ColorSector::Green,
+		h if h < 195.0 => ColorSector::Cyan,
+		h if h < 255.0 => ColorSector::Blue,
+		h if h < 285.0 => ColorSector::Violet,
+		h if h < 315.0 => ColorSector::Magenta,
+		h if h < 345.0 => ColorSector::Pink,
+		_ => ColorSector::Red,
+	})
+}
+
+/// Coarse hue range of a color as commonly used by design systems.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum HueCategory {
+	Red,
+	Orange,
+	Yellow,
+	Green,
+	Cyan,
+	Blue,
+	Violet,
+	Magenta,
+
+	/// The color is a shade of gray, so it has no meaningful hue.
+	Achromatic,
+}
+
+/// Classifies the color by its HSL hue.
+/// Unlike [`color_wheel_sector`], only exact grays are achromatic, see [`is_achromatic`].
+/// Ranges in degrees are red 345–15, orange 15–45, yellow 45–75, green 75–165, cyan 165–195,
+/// blue 195–255, violet 255–285 and magenta 285–345.
+pub fn hue_category(color: &Rgb) -> HueCategory {
+	if is_achromatic(color) {
+		return HueCategory::Achromatic;
+	}
+	let hsl: Hsl = (*color).into_color();
+	match hsl.hue.into_positive_degrees() {
+		h if h < 15.0 => HueCategory::Red,
+		h if h < 45.0 => HueCategory::Orange,
+		h if h < 75.0 => HueCategory::Yellow,
+		h if h < 165.0 => HueCategory::Green,
+		h if h < 195.0 => HueCategory::Cyan,
+		h if h < 255.0 => HueCategory::Blue,
+		h if h < 285.0 => HueCategory::Violet,
+		h if h < 345.0 => HueCategory::Magenta,
+		_ => HueCategory::Red,
+	}
+}
+
+/// The RGB channel with the highest value.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DominantChannel {
+	Red,
+	Green,
+	Blue,
+
+	/// No channel is dominant as the color has low saturation.
+	Gray,
+}
+
+/// Classifies the color by its dominant channel without converting it to another color space.
+/// If multiple channels have the highest value, the first one in RGB order is used.
+pub fn dominant_channel(color: &Rgb) -> DominantChannel {
+	let max = color.red.max(color.green).max(color.blue);
+	let min = color.red.min(color.green).min(color.blue);
+
+	if max - min < 0.1 {
+		DominantChannel::Gray
+	} else if color.red == max {
+		DominantChannel::Red
+	} else if color.green == max {
+		DominantChannel::Green
+	} else {
+		DominantChannel::Blue
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use palette::{FromColor, Hsl, Hwb};
+
+	use super::*;
+
+	fn assert_components_eq(actual: (f32, f32, f32), expected: (f32, f32, f32)) {
+		assert!(
+			(actual.0 - expected.0).abs() < 0.001
+				&& (actual.1 - expected.1).abs() < 0.001
+				&& (actual.2 - expected.2).abs() < 0.001,
+			"{:?} != {:?}",
+			actual,
+			expected
+		);
+	}
+
+	const HWB_TEST_COLORS: [(f32, f32, f32); 6] = [
+		(0.0, 0.0, 0.0),
+		(0.0, 0.0, 1.0),
+		(0.0, 1.0, 0.0),
+		(120.0, 0.2, 0.3),
+		(240.0, 0.5, 0.25),
+		(330.0, 0.1, 0.6),
+	];
+
+	#[test]
+	fn hsl_to_hwb_matches_palette() {
+		let hsl: Hsl = Hsl::new(210.0, 0.6, 0.3);
+		let expected: Hwb = Hwb::from_color(hsl);
+
+		assert_components_eq(
+			hsl_to_hwb(210.0, 0.6, 0.3),
+			(
+				expected.hue.into_positive_degrees(),
+				expected.whiteness,
+				expected.blackness,
+			),
+		);
+	}
+
+	#[test]
+	fn hwb_to_hsl_matches_palette() {
+		let hwb: Hwb = Hwb::new(210.0, 0.2, 0.3);
+		let expected: Hsl = Hsl::from_color(hwb);
+
+		assert_components_eq(
+			hwb_to_hsl(210.0, 0.2, 0.3),
+			(
+				expected.hue.into_positive_degrees(),
+				expected.saturation,
+				expected.lightness,
+			),
+		);
+	}
+
+	#[test]
+	fn hwb_to_hsl_achromatic() {
+		assert_components_eq(hwb_to_hsl(90.0, 0.6, 0.6), (90.0, 0.0, 0.5));
+	}
+
+	#[test]
+	fn hsl_to_hwb_round_trip() {
+		for (hue, whiteness, blackness) in HWB_TEST_COLORS {
+			let (h, s, l) = hwb_to_hsl(hue, whiteness, blackness);
+
+			assert_components_eq(hsl_to_hwb(h, s, l), (hue, whiteness, blackness));
+		}
+	}
+
+	#[test]
+	fn mix_with_gamma_correction_bounds() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let green = Srgba::new(0.0, 1.0, 0.0, 0.5);
+
+		assert_eq!(
+			mix_with_gamma_correction(&red, &green, 0.0).into_format::<u8, u8>(),
+			red.into_format()
+		);
+		assert_eq!(
+			mix_with_gamma_correction(&red, &green, 1.0).into_format::<u8, u8>(),
+			green.into_format()
+		);
+	}
+
+	#[test]
+	fn mix_with_gamma_correction_clamps_ratio() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let green = Srgba::new(0.0, 1.0, 0.0, 1.0);
+
+		assert_eq!(
+			mix_with_gamma_correction(&red, &green, 1.5),
+			mix_with_gamma_correction(&red, &green, 1.0)
+		);
+		assert_eq!(
+			mix_with_gamma_correction(&red, &green, -0.5),
+			mix_with_gamma_correction(&red, &green, 0.0)
+		);
+	}
+
+	#[test]
+	fn mix_with_gamma_correction_avoids_dark_band() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let green = Srgba::new(0.0, 1.0, 0.0, 1.0);
+
+		let result = mix_with_gamma_correction(&red, &green, 0.5);
+		// Naive mixing would result in 0.5 for both channels.
+		assert!((result.red - 0.735).abs() < 0.001);
+		assert!((result.green - 0.735).abs() < 0.001);
+		assert_eq!(result.blue, 0.0);
+		assert_eq!(result.alpha, 1.0);
+	}
+
+	#[test]
+	fn mix_in_oklab_bounds() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let green = Srgba::new(0.0, 1.0, 0.0, 0.5);
+
+		assert_eq!(mix_in_oklab(&red, &green, 0.0), red);
+		assert_eq!(mix_in_oklab(&red, &green, 1.0), green);
+	}
+
+	#[test]
+	fn mix_in_oklab_clamps_ratio() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let green = Srgba::new(0.0, 1.0, 0.0, 1.0);
+
+		assert_eq!(mix_in_oklab(&red, &green, -0.5), red);
+		assert_eq!(mix_in_oklab(&red, &green, 1.0000001), green);
+	}
+
+	#[test]
+	fn mix_in_oklab_is_perceptually_uniform() {
+		let black = Srgba::new(0.0, 0.0, 0.0, 1.0);
+		let white = Srgba::new(1.0, 1.0, 1.0, 1.0);
+
+		let result = mix_in_oklab(&black, &white, 0.5);
+		// Oklab lightness of 0.5 is a darker gray than the sRGB midpoint.
+		assert!((result.red - 0.389).abs() < 0.001);
+		assert!((result.green - 0.389).abs() < 0.001);
+		assert!((result.blue - 0.389).abs() < 0.001);
+		assert_eq!(result.alpha, 1.0);
+	}
+
+	#[test]
+	fn lerp_interpolates_all_channels() {
+		let a = Srgba::new(0.0, 0.2, 1.0, 0.0);
+		let b = Srgba::new(1.0, 0.6, 0.0, 1.0);
+
+		assert_eq!(lerp(&a, &b, 0.0), a);
+		assert_eq!(lerp(&a, &b, 1.0), b);
+		assert_eq!(lerp(&a, &b, 0.5), Srgba::new(0.5, 0.4, 0.5, 0.5));
+	}
+
+	#[test]
+	fn lerp_clamps_extrapolation() {
+		let a = Srgba::new(0.0, 0.5, 1.0, 1.0);
+		let b = Srgba::new(1.0, 0.5, 0.0, 1.0);
+
+		assert_eq!(lerp(&a, &b, 2.0), Srgba::new(1.0, 0.5, 0.0, 1.0));
+		assert_eq!(lerp(&a, &b, -1.0), Srgba::new(0.0, 0.5, 1.0, 1.0));
+	}
+
+	#[test]
+	fn premultiply_alpha_multiplies_in_linear_light() {
+		let color = Srgba::new(1.0, 0.5, 0.0, 0.5);
+
+		let result = premultiply_alpha(&color).into_linear::<f32, f32>();
+		let expected = color.into_linear::<f32, f32>();
+		assert!((result.red - 0.5).abs() < 0.001);
+		assert!((result.green - expected.green * 0.5).abs() < 0.001);
+		assert_eq!(result.blue, 0.0);
+		assert_eq!(result.alpha, 0.5);
+	}
+
+	#[test]
+	fn unpremultiply_alpha_reverses_premultiply_alpha() {
+		let color = Srgba::new(0.2, 0.4, 0.8, 0.25);
+
+		assert_eq!(
+			unpremultiply_alpha(&premultiply_alpha(&color)).into_format::<u8, u8>(),
+			color.into_format::<u8, u8>()
+		);
+	}
+
+	#[test]
+	fn unpremultiply_alpha_transparent_is_black() {
+		assert_eq!(
+			unpremultiply_alpha(&Srgba::new(0.2, 0.4, 0.8, 0.0)),
+			Srgba::new(0.0, 0.0, 0.0, 0.0)
+		);
+	}
+
+	#[test]
+	fn alpha_composite_over_opaque_source() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 1.0);
+		let white = Srgba::new(1.0, 1.0, 1.0, 1.0);
+
+		assert_eq!(
+			alpha_composite_over(&red, &white).into_format::<u8, u8>(),
+			red.into_format()
+		);
+	}
+
+	#[test]
+	fn alpha_composite_over_transparent_source() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 0.0);
+		let blue = Srgba::new(0.0, 0.0, 1.0, 0.5);
+
+		assert_eq!(
+			alpha_composite_over(&red, &blue).into_format::<u8, u8>(),
+			blue.into_format()
+		);
+	}
+
+	#[test]
+	fn alpha_composite_over_semi_transparent_source() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 0.5);
+		let white = Srgba::new(1.0, 1.0, 1.0, 1.0);
+
+		let result = alpha_composite_over(&red, &white);
+		assert!((result.red - 1.0).abs() < 0.001);
+		assert!((result.green - 0.735).abs() < 0.001);
+		assert!((result.blue - 0.735).abs() < 0.001);
+		assert!((result.alpha - 1.0).abs() < 0.001);
+	}
+
+	#[test]
+	fn rotate_hue_finds_complement() {
+		let red = Srgba::new(1.0, 0.0, 0.0, 0.5);
+
+		assert_eq!(
+			rotate_hue(&red, 180.0).into_format::<u8, u8>(),
+			Srgba::new(0.0, 1.0, 1.0, 0.5).into_format()
+		);
+	}
+
+	#[test]
+	fn rotate_hue_preserves_saturation_and_lightness() {
+		let color = Srgba::new(0.8, 0.4, 0.2, 1.0);
+		let original: Hsla = color.into_color();
+
+		let rotated: Hsla = rotate_hue(&color, 150.0).into_color();
+		assert!(
+			(rotated.hue.into_positive_degrees() - original.hue.into_positive_degrees() - 150.0)
+				.abs() < 0.01
+		);
+		assert!((rotated.saturation - original.saturation).abs() < 0.001);
+		assert!((rotated.lightness - original.lightness).abs() < 0.001);
+	}
+
+	#[test]
+	fn lighten_and_darken_adjust_lightness() {
+		let color = Rgb::from_color(Hsl::new(0.0, 1.0, 0.5));
+
+		let lightened: Hsl = lighten(&color, 0.2).into_color();
+		assert!((lightened.lightness - 0.7).abs() < 0.001);
+
+		let darkened: Hsl = darken(&color, 0.2).into_color();
+		assert!((darkened.lightness - 0.3).abs() < 0.001);
+
+		assert_eq!(lighten(&color, 0.8), Rgb::new(1.0, 1.0, 1.0));
+		assert_eq!(darken(&color, 0.8), Rgb::new(0.0, 0.0, 0.0));
+	}
+
+	#[test]
+	fn saturate_and_desaturate_adjust_saturation() {
+		let color = Rgb::from_color(Hsl::new(0.0, 0.5, 0.5));
+
+		let saturated: Hsl = saturate(&color, 0.2).into_color();
+		assert!((saturated.saturation - 0.7).abs() < 0.001);
+
+		let desaturated: Hsl = desaturate(&color, 0.2).into_color();
+		assert!((desaturated.saturation - 0.3).abs() < 0.001);
+
+		assert_eq!(desaturate(&color, 0.8), Rgb::new(0.5, 0.5, 0.5));
+	}
+
+	#[test]
+	fn tint_bounds() {
+		let color = Rgb::new(0.2, 0.4, 0.6);
+
+		assert_eq!(tint(&color, 0.0), color);
+		assert_eq!(
+			tint(&color, 1.0).into_format::<u8>(),
+			Rgb::<_, u8>::new(255, 255, 255)
+		);
+		assert_eq!(
+			tint(&color, 2.0).into_format::<u8>(),
+			Rgb::<_, u8>::new(255, 255, 255)
+		);
+	}
+
+	#[test]
+	fn shade_bounds() {
+		let color = Rgb::new(0.2, 0.4, 0.6);
+
+		assert_eq!(shade(&color, 0.0), color);
+		assert_eq!(
+			shade(&color, 1.0).into_format::<u8>(),
+			Rgb::<_, u8>::new(0, 0, 0)
+		);
+		assert_eq!(shade(&color, -1.0), color);
+	}
+
+	#[test]
+	fn tone_bounds() {
+		let color = Rgb::new(0.2, 0.4, 0.6);
+
+		assert_eq!(tone(&color, 0.0), color);
+		assert_eq!(
+			tone(&color, 1.0).into_format::<u8>(),
+			Rgb::<_, u8>::new(128, 128, 128)
+		);
+	}
+
+	#[test]
+	fn invert_preserves_alpha() {
+		let color = Srgba::new(1.0, 0.25, 0.0, 0.5);
+
+		assert_eq!(invert(&color), Srgba::new(0.0, 0.75, 1.0, 0.5));
+	}
+
+	#[test]
+	fn to_web_safe_rounds_channels() {
+		let color: Rgb = Rgb::<_, u8>::new(0x30, 0x80, 0xFE).into_format();
+
+		let web_safe = to_web_safe(&color);
+
+		assert_eq!(
+			web_safe.into_format::<u8>(),
+			Rgb::<_, u8>::new(0x33, 0x99, 0xFF)
+		);
+		assert!(is_web_safe(&web_safe));
+	}
+
+	#[test]
+	fn is_web_safe_rejects_other_values() {
+		assert!(is_web_safe(
+			&Rgb::<_, u8>::new(0x00, 0x66, 0xCC).into_format()
+		));
+		assert!(!is_web_safe(
+			&Rgb::<_, u8>::new(0x00, 0x66, 0xCD).into_format()
+		));
+	}
+
+	#[test]
+	fn to_grayscale_uses_relative_luminance() {
+		let color = Srgba::new(0.0, 1.0, 0.0, 0.5);
+
+		let result = to_grayscale(&color);
+		// Relative luminance of green is 0.7152.
+		assert_eq!(
+			result.into_format::<u8, u8>(),
+			Srgba::<u8>::new(220, 220, 220, 128)
+		);
+	}
+
+	#[test]
+	fn color_wheel_position_chromatic() {
+		let color = Rgb::new(0.0, 1.0, 1.0);
+
+		assert_eq!(color_wheel_position(&color), Some(180.0));
+	}
+
+	#[test]
+	fn color_wheel_position_achromatic() {
+		let color = Rgb::new(0.5, 0.5, 0.52);
+
+		assert_eq!(color_wheel_position(&color), None);
+	}
+
+	#[test]
+	fn color_wheel_sector_finds_sector() {
+		assert_eq!(
+			color_wheel_sector(&Rgb::new(1.0, 0.0, 0.0)),
+			Some(ColorSector::Red)
+		);
+		assert_eq!(
+			color_wheel_sector(&Rgb::new(1.0, 0.0, 0.1)),
+			Some(ColorSector::Red)
+		);
+		assert_eq!(
+			color_wheel_sector(&Rgb::new(1.0, 0.5, 0.0)),
+			Some(ColorSector::Orange)
+		);
+		assert_eq!(
+			color_wheel_sector(&Rgb::new(0.0, 0.0, 1.0)),
+			Some(ColorSector::Blue)
+		);
+		assert_eq!(
+			color_wheel_sector(&Rgb::new(1.0, 0.0, 0.5)),
+			Some(ColorSector::Pink)
+		);
+		assert_eq!(color_wheel_sector(&Rgb::new(1.0, 1.0, 1.0)), None);
+	}
+
+	#[test]
+	fn hue_category_finds_category() {
+		assert_eq!(hue_category(&Rgb::new(1.0, 0.0, 0.1)), HueCategory::Red);
+		assert_eq!(hue_category(&Rgb::new(1.0, 0.5, 0.0)), HueCategory::Orange);
+		assert_eq!(hue_category(&Rgb::new(0.5, 1.0, 0.0)), HueCategory::Green);
+		assert_eq!(hue_category(&Rgb::new(0.0, 1.0, 1.0)), HueCategory::Cyan);
+		assert_eq!(hue_category(&Rgb::new(0.5, 0.0, 1.0)), HueCategory::Violet);
+		assert_eq!(hue_category(&Rgb::new(1.0, 0.0, 1.0)), HueCategory::Magenta);
+	}
+
+	#[test]
+	fn hue_category_achromatic() {
+		assert_eq!(
+			hue_category(&Rgb::new(0.5, 0.5, 0.5)),
+			HueCategory::Achromatic
+		);
+		assert_eq!(hue_category(&Rgb::new(0.5, 0.5, 0.52)), HueCategory::Blue);
+	}
+
+	#[test]
+	fn dominant_channel_chromatic() {
+		assert_eq!(
+			dominant_channel(&Rgb::new(1.0, 0.0, 0.0)),
+			DominantChannel::Red
+		);
+		assert_eq!(
+			dominant_channel(&Rgb::new(0.0, 1.0, 0.0)),
+			DominantChannel::Green
+		);
+		assert_eq!(
+			dominant_channel(&Rgb::new(0.2, 0.4, 0.6)),
+			DominantChannel::Blue
+		);
+	}
+
+	#[test]
+	fn dominant_channel_gray() {
+		assert_eq!(
+			dominant_channel(&Rgb::new(1.0, 1.0, 1.0)),
+			DominantChannel::Gray
+		);
+		assert_eq!(
+			dominant_channel(&Rgb::new(0.0, 0.0, 0.0)),
+			DominantChannel::Gray
+		);
+		assert_eq!(
+			dominant_channel(&Rgb::new(0.5, 0.5, 0.55)),
+			DominantChannel::Gray
+		);
+	}
+}
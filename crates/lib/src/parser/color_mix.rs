@@ -0,0 +1,220 @@
+use std::sync::LazyLock;
+
+use palette::Srgba;
+use regex::Regex;
+
+use crate::blend::{color_mix, ColorMixMethod};
+use crate::gradient::{HueInterpolationMethod, InterpolationColorSpace};
+use crate::parser::css_types::{parse_number, NUMBER_PATTERN};
+use crate::parser::{parse_color_unclamped, split_outside_parentheses, ParsingError};
+
+const COLOR_MIX_PREFIX: &str = "color-mix(";
+
+static INTERPOLATION_METHOD_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(r"(?i)^in\s+(?P<space>[a-z0-9-]+)(?:\s+(?P<hue>[a-z]+)\s+hue)?$").unwrap()
+});
+
+static PERCENTAGE_BEFORE_COLOR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"(?s)^(?P<percentage>{NUMBER_PATTERN})%\s+(?P<color>.+)$"
+	))
+	.unwrap()
+});
+
+static PERCENTAGE_AFTER_COLOR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"(?s)^(?P<color>.+?)\s+(?P<percentage>{NUMBER_PATTERN})%$"
+	))
+	.unwrap()
+});
+
+/// Checks if the string starts like a `color-mix()` function, ignoring case.
+pub(crate) fn is_color_mix_function(seq: &str) -> bool {
+	seq.get(..COLOR_MIX_PREFIX.len())
+		.is_some_and(|start| start.eq_ignore_ascii_case(COLOR_MIX_PREFIX))
+}
+
+/// Splits the arguments of a function at commas outside of parentheses.
+fn split_arguments(seq: &str) -> Vec<&str> {
+	split_outside_parentheses(seq, |c| c == ',')
+		.into_iter()
+		.map(str::trim)
+		.collect()
+}
+
+fn parse_interpolation_method(seq: &str) -> Result<ColorMixMethod, ParsingError> {
+	let captures = INTERPOLATION_METHOD_REGEX.captures(seq).ok_or_else(|| {
+		ParsingError::InvalidSyntax(
+			"Expected color interpolation method in the form 'in <color-space> [<hue-method> hue]'."
+				.into(),
+		)
+	})?;
+
+	let space = match captures["space"].to_ascii_lowercase().as_str() {
+		"srgb" => InterpolationColorSpace::Srgb,
+		"srgb-linear" => InterpolationColorSpace::LinearSrgb,
+		"lab" => InterpolationColorSpace::Lab,
+		"oklab" => InterpolationColorSpace::Oklab,
+		"oklch" => InterpolationColorSpace::Oklch,
+		"hsl" => InterpolationColorSpace::Hsl,
+		_ => {
+			return Err(ParsingError::UnsupportedValue(
+				"Only the color spaces 'srgb', 'srgb-linear', 'lab', 'oklab', 'oklch' and 'hsl' are supported for color-mix()."
+					.into(),
+			))
+		}
+	};
+
+	let hue_interpolation = match captures.name("hue") {
+		None => HueInterpolationMethod::default(),
+		Some(_)
+			if !matches!(
+				space,
+				InterpolationColorSpace::Oklch | InterpolationColorSpace::Hsl
+			) =>
+		{
+			return Err(ParsingError::InvalidSyntax(
+				"Hue interpolation methods are only allowed for color spaces with a hue.".into(),
+			))
+		}
+		Some(hue) => match hue.as_str().to_ascii_lowercase().as_str() {
+			"shorter" => HueInterpolationMethod::Shorter,
+			"longer" => HueInterpolationMethod::Longer,
+			"increasing" => HueInterpolationMethod::Increasing,
+			"decreasing" => HueInterpolationMethod::Decreasing,
+			_ => {
+				return Err(ParsingError::InvalidSyntax(
+					"Expected hue interpolation method 'shorter', 'longer', 'increasing' or 'decreasing'."
+						.into(),
+				))
+			}
+		},
+	};
+
+	Ok(ColorMixMethod {
+		space,
+		hue_interpolation,
+	})
+}
+
+/// Parses a color with an optional percentage before or after it, returning the percentage as a fraction.
+fn parse_mix_color(seq: &str) -> Result<(Srgba, Option<f32>), ParsingError> {
+	let (color_str, percentage_str) = match PERCENTAGE_BEFORE_COLOR_REGEX
+		.captures(seq)
+		.or_else(|| PERCENTAGE_AFTER_COLOR_REGEX.captures(seq))
+	{
+		Some(captures) => (
+			captures.name("color").unwrap().as_str(),
+			Some(captures.name("percentage").unwrap().as_str()),
+		),
+		None => (seq, None),
+	};
+
+	let percentage = match percentage_str {
+		Some(percentage_str) => {
+			let percentage = parse_number(percentage_str) / 100.0;
+			if !(0.0..=1.0).contains(&percentage) {
+				return Err(ParsingError::InvalidSyntax(
+					"Percentages in color-mix() must be between 0% and 100%.".into(),
+				));
+			}
+			Some(percentage)
+		}
+		None => None,
+	};
+	Ok((parse_color_unclamped(color_str)?, percentage))
+}
+
+/// Parses a CSS `color-mix()` function string, e.g. `color-mix(in oklch longer hue, red 40%, blue)`.
+/// The colors may use any syntax supported by [`parse_color_unclamped`], including `color-mix()` itself.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-5/#color-mix)
+/// and [`color_mix`].
+///
+/// # Errors
+/// - If the string is not a valid `color-mix()` function.
+/// - If the color space is not supported, see [`InterpolationColorSpace`].
+/// - If both percentages are 0%.
+pub(crate) fn parse_color_mix_function(seq: &str) -> Result<Srgba, ParsingError> {
+	let trimmed = seq.trim();
+	let arguments = is_color_mix_function(trimmed)
+		.then(|| &trimmed[COLOR_MIX_PREFIX.len()..])
+		.and_then(|rest| rest.strip_suffix(')'))
+		.map(split_arguments)
+		.filter(|arguments| arguments.len() == 3)
+		.ok_or_else(|| {
+			ParsingError::InvalidSyntax(
+				"Expected color-mix() function in the form 'color-mix(in <color-space>, <color> [<percentage>], <color> [<percentage>])'."
+					.into(),
+			)
+		})?;
+
+	let method = parse_interpolation_method(arguments[0])?;
+	let (color_1, percentage_1) = parse_mix_color(arguments[1])?;
+	let (color_2, percentage_2) = parse_mix_color(arguments[2])?;
+
+	let (amount_1, amount_2) = match (percentage_1, percentage_2) {
+		(None, None) => (0.5, 0.5),
+		(Some(percentage_1), None) => (percentage_1, 1.0 - percentage_1),
+		(None, Some(percentage_2)) => (1.0 - percentage_2, percentage_2),
+		(Some(percentage_1), Some(percentage_2)) => (percentage_1, percentage_2),
+	};
+	if amount_1 + amount_2 == 0.0 {
+		return Err(ParsingError::InvalidSyntax(
+			"Percentages in color-mix() must not both be 0%.".into(),
+		));
+	}
+
+	Ok(color_mix(method, &color_1, amount_1, &color_2, amount_2))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_color_mix_function_mixes() {
+		assert_eq!(
+			parse_color_mix_function("color-mix(in srgb, red, blue)")
+				.unwrap()
+				.into_format::<u8, u8>(),
+			Srgba::new(128, 0, 128, 255)
+		);
+		assert_eq!(
+			parse_color_mix_function("COLOR-MIX(in srgb, rgb(255, 0, 0) 75%, blue)")
+				.unwrap()
+				.into_format::<u8, u8>(),
+			Srgba::new(191, 0, 64, 255)
+		);
+		assert_eq!(
+			parse_color_mix_function("color-mix(in srgb, 25% red, blue 25%)")
+				.unwrap()
+				.into_format::<u8, u8>(),
+			Srgba::new(128, 0, 128, 128)
+		);
+	}
+
+	#[test]
+	fn parse_color_mix_function_parses_hue_interpolation_method() {
+		assert_eq!(
+			parse_interpolation_method("in oklch longer hue"),
+			Ok(ColorMixMethod {
+				space: InterpolationColorSpace::Oklch,
+				hue_interpolation: HueInterpolationMethod::Longer,
+			})
+		);
+		assert!(parse_color_mix_function("color-mix(in hsl decreasing hue, red, lime)").is_ok());
+		assert!(parse_color_mix_function("color-mix(in srgb longer hue, red, blue)").is_err());
+	}
+
+	#[test]
+	fn parse_color_mix_function_rejects_invalid() {
+		assert!(parse_color_mix_function("color-mix(in srgb, red)").is_err());
+		assert!(parse_color_mix_function("color-mix(red, blue, in srgb)").is_err());
+		assert!(parse_color_mix_function("color-mix(in srgb, red 0%, blue 0%)").is_err());
+		assert!(parse_color_mix_function("color-mix(in srgb, red 150%, blue)").is_err());
+		assert!(matches!(
+			parse_color_mix_function("color-mix(in xyz, red, blue)"),
+			Err(ParsingError::UnsupportedValue(_))
+		));
+	}
+}
@@ -0,0 +1,100 @@
+use regex::Captures;
+
+use crate::parser::{FunctionChannels, ParsingError};
+
+/// Regex pattern matching a CSS number, e.g. `-1.5`.
+pub(crate) const NUMBER_PATTERN: &str = r"[+-]?(?:\d+(?:\.\d+)?|\.\d+)";
+
+/// Regex pattern matching an optional alpha value including its unit, captured as `alpha`.
+/// The value may be [`NONE_KEYWORD`].
+pub(crate) const ALPHA_PATTERN: &str =
+	r"(?:/\s*(?P<alpha>[+-]?(?:\d+(?:\.\d+)?|\.\d+)%?|none)\s*)?";
+
+/// Keyword for a missing channel.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#missing).
+pub(crate) const NONE_KEYWORD: &str = "none";
+
+/// Parses a number whose syntax was already validated by [`NUMBER_PATTERN`].
+pub(crate) fn parse_number(seq: &str) -> f32 {
+	seq.parse().unwrap()
+}
+
+/// Parses a hue in degrees whose syntax was already validated, with an optional `deg` unit.
+pub(crate) fn parse_hue(seq: &str) -> f32 {
+	parse_number(seq.trim_end_matches(|c: char| c.is_ascii_alphabetic()))
+}
+
+/// Parses a channel with `parse`, unless it is [`NONE_KEYWORD`], in which case `None` is returned.
+///
+/// # Errors
+/// - If `parse` fails.
+pub(crate) fn parse_optional<F>(seq: &str, parse: F) -> Result<Option<f32>, ParsingError>
+where
+	F: FnOnce(&str) -> Result<f32, ParsingError>,
+{
+	if seq.eq_ignore_ascii_case(NONE_KEYWORD) {
+		Ok(None)
+	} else {
+		parse(seq).map(Some)
+	}
+}
+
+/// Resolves missing channels to `0`, as CSS does when rendering a color.
+pub(crate) fn resolve_missing(channels: FunctionChannels) -> [f32; 4] {
+	channels.map(|channel| channel.unwrap_or(0.0))
+}
+
+/// Clamps a value to the range 0 to 1.
+pub(crate) fn clamp_fraction(val: f32) -> f32 {
+	val.clamp(0.0, 1.0)
+}
+
+/// How values outside of their valid range are handled when parsing.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub(crate) enum ParseMode {
+	/// Values out of range are clamped.
+	Clamp,
+
+	/// Values out of range are rejected.
+	Strict,
+}
+
+/// Converts a value to a fraction in the range 0 to 1 according to the parse mode.
+///
+/// # Errors
+/// - If the mode is [`ParseMode::Strict`] and the value is out of range.
+///   `seq` is the original input of the value, used for the error message.
+pub(crate) fn to_fraction(val: f32, seq: &str, mode: ParseMode) -> Result<f32, ParsingError> {
+	match mode {
+		ParseMode::Clamp => Ok(clamp_fraction(val)),
+		ParseMode::Strict if (0.0..=1.0).contains(&val) => Ok(val),
+		ParseMode::Strict => Err(ParsingError::InvalidSyntax(
+			format!("Value '{}' is out of range.", seq).into(),
+		)),
+	}
+}
+
+/// Parses a percentage whose number was captured without the `%` sign to a fraction.
+///
+/// # Errors
+/// - If the mode is [`ParseMode::Strict`] and the value is out of range.
+pub(crate) fn parse_percentage(seq: &str, mode: ParseMode) -> Result<f32, ParsingError> {
+	to_fraction(parse_number(seq) / 100.0, &format!("{}%", seq), mode)
+}
+
+/// Parses the alpha value captured by [`ALPHA_PATTERN`], defaulting to opaque if it is missing
+/// and returning `None` if it is [`NONE_KEYWORD`].
+///
+/// # Errors
+/// - If the mode is [`ParseMode::Strict`] and the value is out of range.
+pub(crate) fn parse_alpha_value(
+	captures: &Captures,
+	mode: ParseMode,
+) -> Result<Option<f32>, ParsingError> {
+	captures.name("alpha").map_or(Ok(Some(1.0)), |alpha| {
+		parse_optional(alpha.as_str(), |seq| match seq.strip_suffix('%') {
+			Some(percentage) => parse_percentage(percentage, mode),
+			None => to_fraction(parse_number(seq), seq, mode),
+		})
+	})
+}
@@ -0,0 +1,128 @@
+use std::sync::LazyLock;
+
+use palette::{Hsla, RgbHue};
+use regex::Regex;
+
+use crate::parser::css_types::{
+	parse_alpha_value, parse_hue, parse_optional, parse_percentage, resolve_missing, ParseMode,
+	ALPHA_PATTERN, NUMBER_PATTERN,
+};
+use crate::parser::{FunctionChannels, ParsingError};
+
+static HSL_FUNCTION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"(?i)^hsla?\(\s*(?P<hue>{NUMBER_PATTERN}(?:deg)?|none)\s+(?P<saturation>{NUMBER_PATTERN}%|none)\s+(?P<lightness>{NUMBER_PATTERN}%|none)\s*{ALPHA_PATTERN}\)$",
+	))
+	.unwrap()
+});
+
+/// Parses a CSS-style HSL function string, e.g. `hsl(180deg 50% 75% / 0.5)`.
+/// Values out of range are clamped, channels given as `none` are treated as `0`.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#the-hsl-notation).
+///
+/// # Errors
+/// - If the string is not a valid HSL function.
+pub fn from_hsl_function_str(seq: &str) -> Result<Hsla, ParsingError> {
+	parse_hsl_function(seq, ParseMode::Clamp)
+}
+
+/// Parses the channels of an HSL function string, with the hue in degrees and the other channels as fractions,
+/// handling values out of range according to `mode`.
+pub(crate) fn parse_hsl_function_channels(
+	seq: &str,
+	mode: ParseMode,
+) -> Result<FunctionChannels, ParsingError> {
+	let captures = HSL_FUNCTION_REGEX.captures(seq.trim()).ok_or_else(|| {
+		ParsingError::InvalidSyntax(
+			"Expected HSL function in the form 'hsl(H S% L%)' or 'hsl(H S% L% / A)'.".into(),
+		)
+	})?;
+
+	let parse_percentage_channel = |name: &str| {
+		parse_optional(&captures[name], |seq| {
+			parse_percentage(seq.trim_end_matches('%'), mode)
+		})
+	};
+	Ok([
+		parse_optional(&captures["hue"], |seq| Ok(parse_hue(seq)))?,
+		parse_percentage_channel("saturation")?,
+		parse_percentage_channel("lightness")?,
+		parse_alpha_value(&captures, mode)?,
+	])
+}
+
+/// Parses an HSL function string, handling values out of range according to `mode`.
+pub(crate) fn parse_hsl_function(seq: &str, mode: ParseMode) -> Result<Hsla, ParsingError> {
+	let [hue, saturation, lightness, alpha] =
+		resolve_missing(parse_hsl_function_channels(seq, mode)?);
+	Ok(Hsla::new(
+		RgbHue::from_degrees(hue),
+		saturation,
+		lightness,
+		alpha,
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_hsl_function_str_opaque() {
+		let result = from_hsl_function_str("hsl(180deg 50% 75%)");
+
+		assert_eq!(
+			result,
+			Ok(Hsla::new(RgbHue::from_degrees(180.0), 0.5, 0.75, 1.0))
+		);
+	}
+
+	#[test]
+	fn from_hsl_function_str_alpha() {
+		assert_eq!(
+			from_hsl_function_str("hsl(180 50% 75% / 0.5)"),
+			Ok(Hsla::new(RgbHue::from_degrees(180.0), 0.5, 0.75, 0.5))
+		);
+		assert_eq!(
+			from_hsl_function_str("hsl(180 50% 75% / 50%)"),
+			Ok(Hsla::new(RgbHue::from_degrees(180.0), 0.5, 0.75, 0.5))
+		);
+	}
+
+	#[test]
+	fn from_hsl_function_str_case_insensitive() {
+		assert_eq!(
+			from_hsl_function_str("HSL(180DEG 50% 75%)"),
+			Ok(Hsla::new(RgbHue::from_degrees(180.0), 0.5, 0.75, 1.0))
+		);
+	}
+
+	#[test]
+	fn from_hsl_function_str_clamps() {
+		assert_eq!(
+			from_hsl_function_str("hsl(180deg 150% -10% / 2)"),
+			Ok(Hsla::new(RgbHue::from_degrees(180.0), 1.0, 0.0, 1.0))
+		);
+	}
+
+	#[test]
+	fn from_hsl_function_str_rejects_invalid() {
+		assert!(from_hsl_function_str("hsl(180deg 50 75%)").is_err());
+		assert!(from_hsl_function_str("hsl(180% 50% 75%)").is_err());
+		assert!(from_hsl_function_str("hsl(180deg, 50%, 75%)").is_err());
+		assert!(from_hsl_function_str("rgb(180 50% 75%)").is_err());
+	}
+
+	#[test]
+	fn from_hsl_function_str_parses_none() {
+		assert_eq!(
+			from_hsl_function_str("hsl(none 100% 50% / none)"),
+			Ok(Hsla::new(RgbHue::from_degrees(0.0), 1.0, 0.5, 0.0))
+		);
+		assert_eq!(
+			parse_hsl_function_channels("hsl(none 100% 50%)", ParseMode::Clamp),
+			Ok([None, Some(1.0), Some(0.5), Some(1.0)])
+		);
+		assert!(from_hsl_function_str("hsl(nonedeg 100% 50%)").is_err());
+	}
+}
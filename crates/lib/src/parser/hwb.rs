@@ -0,0 +1,143 @@
+use std::sync::LazyLock;
+
+use palette::{Hwba, RgbHue};
+use regex::Regex;
+
+use crate::parser::css_types::{
+	parse_alpha_value, parse_hue, parse_optional, parse_percentage, resolve_missing, ParseMode,
+	ALPHA_PATTERN, NUMBER_PATTERN,
+};
+use crate::parser::{FunctionChannels, ParsingError};
+
+static HWB_FUNCTION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"(?i)^hwb\(\s*(?P<hue>{NUMBER_PATTERN}(?:deg)?|none)\s+(?P<whiteness>{NUMBER_PATTERN}%|none)\s+(?P<blackness>{NUMBER_PATTERN}%|none)\s*{ALPHA_PATTERN}\)$",
+	))
+	.unwrap()
+});
+
+/// Parses a CSS-style HWB function string, e.g. `hwb(180deg 20% 30% / 0.5)`.
+/// Values out of range are clamped, channels given as `none` are treated as `0`.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#the-hwb-notation).
+///
+/// # Errors
+/// - If the string is not a valid HWB function.
+/// - If the sum of whiteness and blackness exceeds 100%.
+pub fn from_hwb_function_str(seq: &str) -> Result<Hwba, ParsingError> {
+	parse_hwb_function(seq, ParseMode::Clamp)
+}
+
+/// Parses the channels of an HWB function string, with the hue in degrees and the other channels as fractions,
+/// handling values out of range according to `mode`.
+///
+/// # Errors
+/// - If the sum of whiteness and blackness exceeds 100%.
+pub(crate) fn parse_hwb_function_channels(
+	seq: &str,
+	mode: ParseMode,
+) -> Result<FunctionChannels, ParsingError> {
+	let captures = HWB_FUNCTION_REGEX.captures(seq.trim()).ok_or_else(|| {
+		ParsingError::InvalidSyntax(
+			"Expected HWB function in the form 'hwb(H W% B%)' or 'hwb(H W% B% / A)'.".into(),
+		)
+	})?;
+
+	let parse_percentage_channel = |name: &str| {
+		parse_optional(&captures[name], |seq| {
+			parse_percentage(seq.trim_end_matches('%'), mode)
+		})
+	};
+	let whiteness = parse_percentage_channel("whiteness")?;
+	let blackness = parse_percentage_channel("blackness")?;
+	if whiteness.unwrap_or(0.0) + blackness.unwrap_or(0.0) > 1.0 {
+		return Err(ParsingError::InvalidSyntax(
+			"Sum of whiteness and blackness must not exceed 100%.".into(),
+		));
+	}
+	Ok([
+		parse_optional(&captures["hue"], |seq| Ok(parse_hue(seq)))?,
+		whiteness,
+		blackness,
+		parse_alpha_value(&captures, mode)?,
+	])
+}
+
+/// Parses an HWB function string, handling values out of range according to `mode`.
+pub(crate) fn parse_hwb_function(seq: &str, mode: ParseMode) -> Result<Hwba, ParsingError> {
+	let [hue, whiteness, blackness, alpha] =
+		resolve_missing(parse_hwb_function_channels(seq, mode)?);
+	Ok(Hwba::new(
+		RgbHue::from_degrees(hue),
+		whiteness,
+		blackness,
+		alpha,
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_hwb_function_str_opaque() {
+		assert_eq!(
+			from_hwb_function_str("hwb(180deg 20% 30%)"),
+			Ok(Hwba::new(RgbHue::from_degrees(180.0), 0.2, 0.3, 1.0))
+		);
+		assert_eq!(
+			from_hwb_function_str("hwb(180 20% 30%)"),
+			Ok(Hwba::new(RgbHue::from_degrees(180.0), 0.2, 0.3, 1.0))
+		);
+	}
+
+	#[test]
+	fn from_hwb_function_str_alpha() {
+		assert_eq!(
+			from_hwb_function_str("hwb(180deg 20% 30% / 0.5)"),
+			Ok(Hwba::new(RgbHue::from_degrees(180.0), 0.2, 0.3, 0.5))
+		);
+	}
+
+	#[test]
+	fn from_hwb_function_str_case_insensitive() {
+		assert_eq!(
+			from_hwb_function_str("HWB(180DEG 20% 30%)"),
+			Ok(Hwba::new(RgbHue::from_degrees(180.0), 0.2, 0.3, 1.0))
+		);
+	}
+
+	#[test]
+	fn from_hwb_function_str_clamps() {
+		assert_eq!(
+			from_hwb_function_str("hwb(180deg 120% -10%)"),
+			Ok(Hwba::new(RgbHue::from_degrees(180.0), 1.0, 0.0, 1.0))
+		);
+	}
+
+	#[test]
+	fn from_hwb_function_str_rejects_whiteness_blackness_sum() {
+		assert!(matches!(
+			from_hwb_function_str("hwb(180deg 60% 60%)"),
+			Err(ParsingError::InvalidSyntax(_))
+		));
+	}
+
+	#[test]
+	fn from_hwb_function_str_rejects_invalid() {
+		assert!(from_hwb_function_str("hwb(180deg 20 30%)").is_err());
+		assert!(from_hwb_function_str("hwba(180deg 20% 30%)").is_err());
+		assert!(from_hwb_function_str("hsl(180deg 20% 30%)").is_err());
+	}
+
+	#[test]
+	fn from_hwb_function_str_parses_none() {
+		assert_eq!(
+			from_hwb_function_str("hwb(180deg none 100%)"),
+			Ok(Hwba::new(RgbHue::from_degrees(180.0), 0.0, 1.0, 1.0))
+		);
+		assert_eq!(
+			parse_hwb_function_channels("hwb(180deg none 100%)", ParseMode::Clamp),
+			Ok([Some(180.0), None, Some(1.0), Some(1.0)])
+		);
+	}
+}
@@ -0,0 +1,207 @@
+use palette::Srgba;
+
+use crate::parser::css_types::clamp_fraction;
+use crate::parser::ParsingError;
+
+/// Minimal cursor over the input, consuming it from the front.
+struct Cursor<'a> {
+	rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+	fn new(seq: &'a str) -> Self {
+		Cursor { rest: seq }
+	}
+
+	fn is_empty(&self) -> bool {
+		self.rest.is_empty()
+	}
+
+	/// Consumes whitespace, returning if any was consumed.
+	fn skip_whitespace(&mut self) -> bool {
+		let trimmed = self.rest.trim_start();
+		let skipped = trimmed.len() != self.rest.len();
+		self.rest = trimmed;
+		skipped
+	}
+
+	/// Consumes the prefix if present, ignoring ASCII case.
+	fn eat(&mut self, prefix: &str) -> bool {
+		match self.rest.get(..prefix.len()) {
+			Some(start) if start.eq_ignore_ascii_case(prefix) => {
+				self.rest = &self.rest[prefix.len()..];
+				true
+			}
+			_ => false,
+		}
+	}
+
+	/// Consumes ASCII digits, returning how many were consumed.
+	fn digits(&mut self) -> usize {
+		let end = self
+			.rest
+			.find(|c: char| !c.is_ascii_digit())
+			.unwrap_or(self.rest.len());
+		self.rest = &self.rest[end..];
+		end
+	}
+
+	/// Consumes a CSS number, e.g. `-1.5`.
+	fn number(&mut self) -> Option<f32> {
+		let start = self.rest;
+		let _ = self.eat("+") || self.eat("-");
+		let integer_digits = self.digits();
+		let fraction_digits = if self.eat(".") {
+			match self.digits() {
+				0 => return None,
+				fraction_digits => fraction_digits,
+			}
+		} else {
+			0
+		};
+		if integer_digits == 0 && fraction_digits == 0 {
+			return None;
+		}
+		start[..start.len() - self.rest.len()].parse().ok()
+	}
+
+	/// Consumes a color channel, either a number from 0 to 255 or a percentage.
+	fn color_channel(&mut self) -> Option<f32> {
+		let number = self.number()?;
+		if self.eat("%") {
+			Some(clamp_fraction(number / 100.0))
+		} else {
+			Some(clamp_fraction(number / 255.0))
+		}
+	}
+
+	/// Consumes an alpha value, either a number from 0 to 1 or a percentage.
+	fn alpha(&mut self) -> Option<f32> {
+		let number = self.number()?;
+		if self.eat("%") {
+			Some(clamp_fraction(number / 100.0))
+		} else {
+			Some(clamp_fraction(number))
+		}
+	}
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+	(c as char).to_digit(16).map(|digit| digit as u8)
+}
+
+fn parse_hex(digits: &str) -> Option<Srgba> {
+	let bytes = digits.as_bytes();
+	let channels: Vec<u8> = match bytes.len() {
+		3 | 4 => bytes
+			.iter()
+			.map(|&c| hex_digit(c).map(|digit| digit * 17))
+			.collect::<Option<_>>()?,
+		6 | 8 => bytes
+			.chunks(2)
+			.map(|pair| Some(hex_digit(pair[0])? * 16 + hex_digit(pair[1])?))
+			.collect::<Option<_>>()?,
+		_ => return None,
+	};
+	let alpha = channels.get(3).copied().unwrap_or(u8::MAX);
+	Some(Srgba::<u8>::new(channels[0], channels[1], channels[2], alpha).into_format())
+}
+
+fn parse_rgb_function(cursor: &mut Cursor) -> Option<Srgba> {
+	if !(cursor.eat("rgba(") || cursor.eat("rgb(")) {
+		return None;
+	}
+	cursor.skip_whitespace();
+	let red = cursor.color_channel()?;
+	if !cursor.skip_whitespace() {
+		return None;
+	}
+	let green = cursor.color_channel()?;
+	if !cursor.skip_whitespace() {
+		return None;
+	}
+	let blue = cursor.color_channel()?;
+	cursor.skip_whitespace();
+	let alpha = if cursor.eat("/") {
+		cursor.skip_whitespace();
+		let alpha = cursor.alpha()?;
+		cursor.skip_whitespace();
+		alpha
+	} else {
+		1.0
+	};
+	if !cursor.eat(")") || !cursor.is_empty() {
+		return None;
+	}
+	Some(Srgba::new(red, green, blue, alpha))
+}
+
+/// Parses a CSS hex color (e.g. `#FF8000`) or an RGB function in the modern syntax
+/// (e.g. `rgb(255 128 0 / 0.5)`) without using regular expressions.
+/// Values out of range are clamped.
+///
+/// This only supports a subset of the formats of [`crate::parser::parse_color`], but is faster,
+/// making it suitable for hot paths.
+///
+/// # Errors
+/// - If the string is neither a hex color nor an RGB function in the modern syntax.
+pub fn parse_rgb_fast(seq: &str) -> Result<Srgba, ParsingError> {
+	let seq = seq.trim();
+	let color = match seq.strip_prefix('#') {
+		Some(digits) => parse_hex(digits),
+		None => parse_rgb_function(&mut Cursor::new(seq)),
+	};
+	color.ok_or_else(|| {
+		ParsingError::InvalidSyntax(
+			"Expected hex color or RGB function in the form 'rgb(R G B)' or 'rgb(R G B / A)'."
+				.into(),
+		)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::parser::{from_rgb_modern_function_str, parse_color};
+
+	use super::*;
+
+	#[test]
+	fn parse_rgb_fast_hex() {
+		for seq in ["#F80", "#F808", "#FF8800", "#ff880088"] {
+			assert_eq!(parse_rgb_fast(seq), parse_color(seq), "{}", seq);
+		}
+	}
+
+	#[test]
+	fn parse_rgb_fast_rgb_function() {
+		for seq in [
+			"rgb(255 128 0)",
+			"RGBA( 255 128 0 / 0.5 )",
+			"rgb(100% 50% 0% / 50%)",
+			"rgb(300 -1 .5/1.5)",
+		] {
+			assert_eq!(
+				parse_rgb_fast(seq),
+				from_rgb_modern_function_str(seq),
+				"{}",
+				seq
+			);
+		}
+	}
+
+	#[test]
+	fn parse_rgb_fast_rejects_invalid() {
+		for seq in [
+			"#FF88F",
+			"#GG8800",
+			"rgb(255, 128, 0)",
+			"rgb(255 128)",
+			"rgb(255 128 0",
+			"rgb(255 128 0) x",
+			"rgb(255 128. 0)",
+			"red",
+		] {
+			assert!(parse_rgb_fast(seq).is_err(), "{}", seq);
+		}
+	}
+}
@@ -0,0 +1,684 @@
+use core::fmt;
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+use cssparser::{ParseError, ParseErrorKind, Parser, ParserInput};
+use cssparser_color::{Color, PredefinedColorSpace};
+use palette::rgb::{Rgb, Rgba};
+use palette::{
+	Clamp, Hsl, Hwb, IntoColor, IntoColorUnclamped, Lab, Lch, LinSrgb, Oklab, Oklch, Srgba,
+	WithAlpha,
+};
+use regex::Regex;
+
+use crate::display_p3::{display_p3_to_srgb, DisplayP3};
+use crate::parser::color_mix::{is_color_mix_function, parse_color_mix_function};
+use crate::parser::css_types::ParseMode;
+use crate::parser::hsl::{parse_hsl_function, parse_hsl_function_channels};
+use crate::parser::hwb::{parse_hwb_function, parse_hwb_function_channels};
+use crate::parser::rgb::{parse_rgb_function, parse_rgb_function_channels};
+use crate::util::{from_linear_srgb, BLACK, WHITE};
+
+pub use crate::parser::hsl::from_hsl_function_str;
+pub use crate::parser::hwb::from_hwb_function_str;
+pub use crate::parser::manual::parse_rgb_fast;
+pub use crate::parser::rgb::{
+	from_rgb_function_str, from_rgb_legacy_function_str, from_rgb_modern_function_str,
+};
+
+mod color_mix;
+mod css_types;
+mod hsl;
+mod hwb;
+mod manual;
+mod rgb;
+
+/// Channels of a color function in the order they are given, e.g. hue, saturation, lightness and alpha for HSL.
+/// Channels given as the keyword `none` are `None`, see [`parse_function_channels`].
+pub type FunctionChannels = [Option<f32>; 4];
+
+/// Error returned when a color string cannot be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsingError {
+	/// The input is not valid CSS.
+	InvalidCss(String),
+
+	/// The input does not have the expected syntax.
+	InvalidSyntax(Cow<'static, str>),
+
+	/// The input is valid CSS color syntax but cannot be represented.
+	UnsupportedValue(Cow<'static, str>),
+
+	/// The input is not a valid hexadecimal color.
+	InvalidHex(String),
+}
+
+impl From<ParseError<'_, ()>> for ParsingError {
+	fn from(err: ParseError<'_, ()>) -> Self {
+		ParsingError::InvalidCss(format!(
+			"{} at L{}:{}.",
+			match err.kind {
+				ParseErrorKind::Basic(kind) => kind.to_string(),
+				ParseErrorKind::Custom(_) => "Unknown error".to_string(),
+			},
+			err.location.line,
+			err.location.column
+		))
+	}
+}
+
+impl Display for ParsingError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ParsingError::InvalidCss(msg) => f.write_str(msg),
+			ParsingError::InvalidSyntax(msg) => f.write_str(msg),
+			ParsingError::UnsupportedValue(msg) => f.write_str(msg),
+			ParsingError::InvalidHex(seq) => {
+				write!(f, "'{}' is not a valid hexadecimal color.", seq)
+			}
+		}
+	}
+}
+
+impl Error for ParsingError {}
+
+/// Parses CSS color string.
+/// Besides the colors supported by `cssparser`, `color-mix()` is supported, see [`crate::blend::color_mix`].
+/// Colors outside of the sRGB gamut (e.g. from `lab()`) are clamped.
+///
+/// # Errors
+/// - If color is keyword 'currentcolor'.
+/// - All other errors: See `cssparser` `Color::parse`.
+pub fn parse_color(seq: &str) -> Result<Rgba, ParsingError> {
+	parse_color_unclamped(seq).map(Clamp::clamp)
+}
+
+/// Parses CSS color string like [`parse_color`], but keeps colors outside of the sRGB gamut unclamped,
+/// so that they can be checked with [`crate::gamut::is_in_srgb_gamut`].
+///
+/// # Errors
+/// - If color is keyword 'currentcolor'.
+/// - All other errors: See `cssparser` `Color::parse`.
+pub fn parse_color_unclamped(seq: &str) -> Result<Rgba, ParsingError> {
+	// Not supported by `cssparser`, so it is parsed separately.
+	if is_color_mix_function(seq.trim()) {
+		return parse_color_mix_function(seq);
+	}
+
+	let mut input = ParserInput::new(seq);
+	let color = Color::parse(&mut Parser::new(&mut input))?;
+
+	match color {
+		Color::ColorFunction(color_function) => {
+			let [c1, c2, c3] = [color_function.c1, color_function.c2, color_function.c3]
+				.map(|channel| channel.unwrap_or(0.0));
+			let rgb = match color_function.color_space {
+				PredefinedColorSpace::Srgb => Rgb::new(c1, c2, c3),
+				PredefinedColorSpace::SrgbLinear => from_linear_srgb(&LinSrgb::new(c1, c2, c3)),
+				PredefinedColorSpace::DisplayP3 => display_p3_to_srgb(&DisplayP3::new(c1, c2, c3)),
+				_ => return Err(ParsingError::UnsupportedValue(
+					"Only the color spaces 'srgb', 'srgb-linear' and 'display-p3' are supported."
+						.into(),
+				)),
+			};
+			Ok(rgb.with_alpha(color_function.alpha.unwrap_or(1.0)))
+		}
+
+		Color::CurrentColor => Err(ParsingError::UnsupportedValue(
+			"currentcolor is not supported in this context.".into(),
+		)),
+
+		// Also covers named colors (including `transparent`), which are resolved to RGBA by `cssparser`.
+		Color::Rgba(rgba) => Ok(Rgb::new(rgba.red, rgba.green, rgba.blue)
+			.with_alpha(rgba.alpha)
+			.into_format()),
+
+		Color::Hsl(hsl) => Ok(Hsl::new(
+			hsl.hue.unwrap_or(0.0),
+			hsl.saturation.unwrap_or(0.0),
+			hsl.lightness.unwrap_or(0.0),
+		)
+		.with_alpha(hsl.alpha.unwrap_or(1.0))
+		.into_color_unclamped()),
+
+		Color::Hwb(hwb) => Ok(Hwb::new(
+			hwb.hue.unwrap_or(0.0),
+			hwb.whiteness.unwrap_or(0.0),
+			hwb.blackness.unwrap_or(0.0),
+		)
+		.with_alpha(hwb.alpha.unwrap_or(1.0))
+		.into_color_unclamped()),
+
+		Color::Lab(lab) => Ok(Lab::new(
+			lab.lightness.unwrap_or(0.0),
+			lab.a.unwrap_or(0.0),
+			lab.b.unwrap_or(0.0),
+		)
+		.with_alpha(lab.alpha.unwrap_or(1.0))
+		.into_color_unclamped()),
+
+		Color::Lch(lch) => Ok(Lch::new(
+			lch.lightness.unwrap_or(0.0),
+			lch.chroma.unwrap_or(0.0),
+			lch.hue.unwrap_or(0.0),
+		)
+		.with_alpha(lch.alpha.unwrap_or(1.0))
+		.into_color_unclamped()),
+
+		Color::Oklab(oklab) => Ok(Oklab::new(
+			oklab.lightness.unwrap_or(0.0),
+			oklab.a.unwrap_or(0.0),
+			oklab.b.unwrap_or(0.0),
+		)
+		.with_alpha(oklab.alpha.unwrap_or(1.0))
+		.into_color_unclamped()),
+
+		Color::Oklch(oklch) => Ok(Oklch::new(
+			oklch.lightness.unwrap_or(0.0),
+			oklch.chroma.unwrap_or(0.0),
+			oklch.hue.unwrap_or(0.0),
+		)
+		.with_alpha(oklch.alpha.unwrap_or(1.0))
+		.into_color_unclamped()),
+	}
+}
+
+/// Parses CSS color string like [`parse_color`], but rejects values out of range instead of clamping them
+/// (e.g. `rgb(300 0 0)`). Useful for tooling that wants to report invalid input.
+///
+/// RGB, HSL and HWB functions are handled by the parsers of this crate,
+/// which only support the syntax of [`from_rgb_function_str`], [`from_hsl_function_str`]
+/// and [`from_hwb_function_str`]. All other formats are parsed with [`parse_color`].
+///
+/// # Errors
+/// - If a color channel or the alpha value is out of range.
+/// - All other errors: See [`parse_color`] and the function-specific parsers.
+pub fn parse_color_strict(seq: &str) -> Result<Rgba, ParsingError> {
+	let trimmed = seq.trim();
+	let has_prefix = |prefix: &str| {
+		trimmed
+			.get(..prefix.len())
+			.is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+	};
+
+	if has_prefix("rgb") {
+		parse_rgb_function(trimmed, ParseMode::Strict)
+	} else if has_prefix("hsl") {
+		parse_hsl_function(trimmed, ParseMode::Strict).map(IntoColor::into_color)
+	} else if has_prefix("hwb") {
+		parse_hwb_function(trimmed, ParseMode::Strict).map(IntoColor::into_color)
+	} else {
+		parse_color(seq)
+	}
+}
+
+/// Splits the string at each separator outside of parentheses,
+/// so that function notations like `rgb(255, 0, 0)` stay intact.
+/// Parts are not trimmed and may be empty, e.g. between two adjacent separators.
+pub fn split_outside_parentheses(seq: &str, is_separator: impl Fn(char) -> bool) -> Vec<&str> {
+	let mut parts = Vec::new();
+	let mut depth = 0;
+	let mut part_start = 0;
+	for (i, c) in seq.char_indices() {
+		match c {
+			'(' => depth += 1,
+			')' => depth -= 1,
+			c if depth == 0 && is_separator(c) => {
+				parts.push(&seq[part_start..i]);
+				part_start = i + c.len_utf8();
+			}
+			_ => {}
+		}
+	}
+	parts.push(&seq[part_start..]);
+	parts
+}
+
+/// Splits a list of colors at whitespace and commas outside of parentheses, see [`split_outside_parentheses`].
+fn split_color_list(seq: &str) -> Vec<&str> {
+	let mut tokens = split_outside_parentheses(seq, |c| c.is_whitespace() || c == ',');
+	tokens.retain(|token| !token.is_empty());
+	tokens
+}
+
+/// Parses a list of CSS color strings separated by whitespace or commas, e.g. `#FFF, rgb(255, 0, 0) red`.
+/// Each color is parsed with [`parse_color`].
+///
+/// A result is returned for every color in the list rather than stopping at the first error,
+/// so callers can collect all colors that could be parsed or report all errors at once.
+pub fn parse_multiple_colors(seq: &str) -> Vec<Result<Rgba, ParsingError>> {
+	split_color_list(seq).into_iter().map(parse_color).collect()
+}
+
+/// Parses a list of CSS color strings like [`parse_multiple_colors`],
+/// but keeps colors outside of the sRGB gamut unclamped, see [`parse_color_unclamped`].
+pub fn parse_multiple_colors_unclamped(seq: &str) -> Vec<Result<Rgba, ParsingError>> {
+	split_color_list(seq)
+		.into_iter()
+		.map(parse_color_unclamped)
+		.collect()
+}
+
+/// Parses the channels of an RGB, HSL or HWB function string, keeping channels given as the keyword `none` as `None`.
+/// Hues are returned in degrees, all other channels as fractions from 0 to 1. Values out of range are clamped.
+///
+/// When rendering, missing channels are treated as `0`, as the other parsers do.
+/// But e.g. when interpolating colors, they are replaced by the channel of the other color instead.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#missing).
+///
+/// # Errors
+/// - If the string is not a valid RGB, HSL or HWB function.
+pub fn parse_function_channels(seq: &str) -> Result<FunctionChannels, ParsingError> {
+	let trimmed = seq.trim();
+	let has_prefix = |prefix: &str| {
+		trimmed
+			.get(..prefix.len())
+			.is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+	};
+
+	if has_prefix("rgb") {
+		parse_rgb_function_channels(trimmed, ParseMode::Clamp)
+	} else if has_prefix("hsl") {
+		parse_hsl_function_channels(trimmed, ParseMode::Clamp)
+	} else if has_prefix("hwb") {
+		parse_hwb_function_channels(trimmed, ParseMode::Clamp)
+	} else {
+		Err(ParsingError::InvalidSyntax(
+			"Expected RGB, HSL or HWB function.".into(),
+		))
+	}
+}
+
+/// Parses CSS color string, returning `fallback` if it cannot be parsed.
+pub fn parse_color_or_default(seq: &str, fallback: Srgba) -> Srgba {
+	parse_color(seq).unwrap_or(fallback)
+}
+
+/// Parses CSS color string, returning opaque black if it cannot be parsed.
+pub fn parse_color_or_black(seq: &str) -> Srgba {
+	parse_color_or_default(seq, BLACK)
+}
+
+/// Parses CSS color string, returning opaque white if it cannot be parsed.
+pub fn parse_color_or_white(seq: &str) -> Srgba {
+	parse_color_or_default(seq, WHITE)
+}
+
+/// Parses an Inkscape-style hex color string, consisting of 8 digits in the order `RRGGBBAA`.
+/// A leading `#` is allowed.
+///
+/// # Errors
+/// - If the string does not consist of 8 hexadecimal digits.
+pub fn from_inkscape_hex(seq: &str) -> Result<Srgba<u8>, ParsingError> {
+	let digits = seq.strip_prefix('#').unwrap_or(seq);
+	if digits.len() != 8 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+		return Err(ParsingError::InvalidHex(String::from(seq)));
+	}
+
+	let channel = |i: usize| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap();
+	Ok(Srgba::new(channel(0), channel(1), channel(2), channel(3)))
+}
+
+/// Parses a 16 bit hex color string, as used by some image editors, with 4 digits per channel
+/// in the order `RRRRGGGGBBBB` or `RRRRGGGGBBBBAAAA`. A leading `#` is allowed.
+///
+/// # Errors
+/// - If the string does not consist of 12 or 16 hexadecimal digits.
+pub fn from_hex16_str(seq: &str) -> Result<Srgba, ParsingError> {
+	let digits = seq.strip_prefix('#').unwrap_or(seq);
+	if !(digits.len() == 12 || digits.len() == 16) || !digits.chars().all(|c| c.is_ascii_hexdigit())
+	{
+		return Err(ParsingError::InvalidHex(String::from(seq)));
+	}
+
+	let channel = |i: usize| u16::from_str_radix(&digits[i * 4..i * 4 + 4], 16).unwrap();
+	let alpha = if digits.len() == 16 {
+		channel(3)
+	} else {
+		u16::MAX
+	};
+	Ok(Srgba::<u16>::new(channel(0), channel(1), channel(2), alpha).into_format())
+}
+
+// Matches the value of each declaration, from its `:` up to the end of the declaration.
+// Selectors like `a:hover {` are not matched, as they are followed by a block instead.
+static CSS_DECLARATION_VALUE_REGEX: LazyLock<Regex> =
+	LazyLock::new(|| Regex::new(r":(?P<value>[^;{}]*)(?:[;}]|$)").unwrap());
+
+// Matches anything that may be a color, non-colors are filtered out by parsing.
+// `var()` and `url()` are matched as a whole so that their contents are not taken for colors.
+static CSS_COLOR_CANDIDATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(
+		r"(?i)\b(?P<skipped>var|url)\((?:[^()]|\([^()]*\))*\)|#[0-9a-f]{3,8}\b|\b(?:rgba?|hsla?|hwb|lab|lch|oklab|oklch|color)\([^()]*\)|[a-z][a-z0-9-]*",
+	)
+	.unwrap()
+});
+
+/// Finds all colors in a CSS string.
+/// Returns each occurrence as a pair of the original string and the parsed color.
+///
+/// This is a heuristic based on the shape of color values, rather than a full CSS parse.
+/// Only declaration values are searched, so selectors like `.red` or `#fade` are ignored,
+/// as are custom properties referenced with `var()` and URLs.
+pub fn extract_colors_from_css(css: &str) -> Vec<(String, Srgba)> {
+	CSS_DECLARATION_VALUE_REGEX
+		.captures_iter(css)
+		.flat_map(|declaration| {
+			let value = declaration.name("value").unwrap().as_str();
+			CSS_COLOR_CANDIDATE_REGEX.captures_iter(value)
+		})
+		.filter(|candidate| candidate.name("skipped").is_none())
+		.filter_map(|candidate| {
+			let candidate = candidate.get(0).unwrap().as_str();
+			parse_color(candidate)
+				.ok()
+				.map(|color| (String::from(candidate), color))
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::gamut::is_in_srgb_gamut;
+	use crate::util::to_channel_bytes_exact;
+
+	use super::*;
+
+	#[test]
+	fn parse_color_parses_hex() {
+		let result = parse_color("#FF0000").unwrap();
+		assert_eq!(result, Srgba::new(1.0, 0.0, 0.0, 1.0));
+	}
+
+	#[test]
+	fn parse_color_parses_named_colors() {
+		assert_eq!(
+			parse_color("red").unwrap().into_format::<u8, u8>(),
+			Srgba::<u8>::new(255, 0, 0, 255)
+		);
+		assert_eq!(
+			parse_color("transparent").unwrap().into_format::<u8, u8>(),
+			Srgba::<u8>::new(0, 0, 0, 0)
+		);
+		assert_eq!(
+			parse_color("RebeccaPurple")
+				.unwrap()
+				.into_format::<u8, u8>(),
+			Srgba::<u8>::new(102, 51, 153, 255)
+		);
+		assert_eq!(
+			parse_color("whitesmoke").unwrap().into_format::<u8, u8>(),
+			Srgba::<u8>::new(245, 245, 245, 255)
+		);
+	}
+
+	#[test]
+	fn parse_color_rejects_invalid() {
+		assert!(matches!(
+			parse_color("foo(1 2 3)"),
+			Err(ParsingError::InvalidCss(_))
+		));
+		assert!(matches!(
+			parse_color("currentcolor"),
+			Err(ParsingError::UnsupportedValue(_))
+		));
+	}
+
+	#[test]
+	fn parse_color_parses_color_function() {
+		assert_eq!(
+			parse_color("color(srgb 1 0 0.5 / 0.5)"),
+			Ok(Srgba::new(1.0, 0.0, 0.5, 0.5))
+		);
+		assert_eq!(
+			parse_color("color(srgb-linear 0 1 0)")
+				.unwrap()
+				.into_format::<u8, u8>(),
+			Srgba::<u8>::new(0, 255, 0, 255)
+		);
+
+		let p3_red = parse_color_unclamped("color(display-p3 1 0 0)").unwrap();
+		assert!(!is_in_srgb_gamut(&p3_red));
+		assert_eq!(parse_color("color(display-p3 1 0 0)"), Ok(p3_red.clamp()));
+	}
+
+	#[test]
+	fn parse_color_parses_color_mix() {
+		assert_eq!(
+			parse_color("color-mix(in srgb, color-mix(in srgb, red, blue) 50%, blue)")
+				.unwrap()
+				.into_format::<u8, u8>(),
+			Srgba::new(64, 0, 191, 255)
+		);
+
+		let mix = "color-mix(in lab, color(display-p3 1 0 0), color(display-p3 1 0 0) 40%)";
+		let p3_red = parse_color_unclamped(mix).unwrap();
+		assert!(!is_in_srgb_gamut(&p3_red));
+		assert_eq!(parse_color(mix), Ok(p3_red.clamp()));
+	}
+
+	#[test]
+	fn parse_color_rejects_unsupported_color_space() {
+		assert!(matches!(
+			parse_color("color(xyz 0 0 0)"),
+			Err(ParsingError::UnsupportedValue(_))
+		));
+	}
+
+	#[test]
+	fn parse_color_or_default_uses_fallback() {
+		let fallback = Srgba::new(0.5, 0.5, 0.5, 1.0);
+
+		assert_eq!(parse_color_or_default("invalid", fallback), fallback);
+		assert_eq!(
+			parse_color_or_default("#00F", fallback),
+			Srgba::new(0.0, 0.0, 1.0, 1.0)
+		);
+	}
+
+	#[test]
+	fn parse_color_or_black_uses_black() {
+		assert_eq!(
+			parse_color_or_black("invalid"),
+			Srgba::new(0.0, 0.0, 0.0, 1.0)
+		);
+	}
+
+	#[test]
+	fn parse_color_or_white_uses_white() {
+		assert_eq!(
+			parse_color_or_white("invalid"),
+			Srgba::new(1.0, 1.0, 1.0, 1.0)
+		);
+	}
+
+	#[test]
+	fn extract_colors_from_css_finds_colors() {
+		let css = ".red { color: #F00; background-color: rgb(0 0 255 / 50%); }
+			#main { border: 1px solid blue; outline-color: #F00; }";
+
+		let result = extract_colors_from_css(css);
+		assert_eq!(
+			result,
+			vec![
+				(String::from("#F00"), Srgba::new(1.0, 0.0, 0.0, 1.0)),
+				(
+					String::from("rgb(0 0 255 / 50%)"),
+					Srgba::new(0.0, 0.0, 1.0, 0.5)
+				),
+				(String::from("blue"), Srgba::new(0.0, 0.0, 1.0, 1.0)),
+				(String::from("#F00"), Srgba::new(1.0, 0.0, 0.0, 1.0)),
+			]
+		);
+	}
+
+	#[test]
+	fn extract_colors_from_css_ignores_non_colors() {
+		let css = ".border-red { display: block; background: url(foo.png); }";
+
+		assert!(extract_colors_from_css(css).is_empty());
+	}
+
+	#[test]
+	fn extract_colors_from_css_ignores_selectors_and_references() {
+		let css = "#fade, a:hover { color: var(--red, blue); background: url(red.png) }
+			.tan { --accent: white; border-color: var(--accent) }";
+
+		assert_eq!(
+			extract_colors_from_css(css),
+			vec![(String::from("white"), Srgba::new(1.0, 1.0, 1.0, 1.0))]
+		);
+	}
+
+	#[test]
+	fn from_inkscape_hex_opaque() {
+		assert_eq!(
+			from_inkscape_hex("11ff0aff"),
+			Ok(Srgba::<u8>::new(0x11, 0xff, 0x0a, 0xff))
+		);
+	}
+
+	#[test]
+	fn from_inkscape_hex_transparent() {
+		assert_eq!(
+			from_inkscape_hex("#11FF0A00"),
+			Ok(Srgba::<u8>::new(0x11, 0xff, 0x0a, 0x00))
+		);
+	}
+
+	#[test]
+	fn from_hex16_str_opaque() {
+		let color = from_hex16_str("#FFFF80000000").unwrap();
+
+		assert_eq!(
+			color.into_format::<u16, u16>(),
+			Srgba::<u16>::new(0xffff, 0x8000, 0x0000, 0xffff)
+		);
+		// 0x8000 is between two 8 bit values.
+		assert_eq!(to_channel_bytes_exact(&color), None);
+	}
+
+	#[test]
+	fn from_hex16_str_transparent() {
+		assert_eq!(
+			from_hex16_str("ffff800000000000")
+				.unwrap()
+				.into_format::<u16, u16>(),
+			Srgba::<u16>::new(0xffff, 0x8000, 0x0000, 0x0000)
+		);
+	}
+
+	#[test]
+	fn from_hex16_str_round_trips_16_bit() {
+		for value in [0x0001, 0x7fff, 0x8000, 0xfffe] {
+			let color = from_hex16_str(&format!("{value:04x}{value:04x}{value:04x}")).unwrap();
+
+			assert_eq!(color.into_format::<u16, u16>().red, value);
+		}
+	}
+
+	#[test]
+	fn from_hex16_str_rejects_invalid() {
+		assert_eq!(
+			from_hex16_str("#FFF"),
+			Err(ParsingError::InvalidHex(String::from("#FFF")))
+		);
+		assert_eq!(
+			from_hex16_str("ffff80000000000g"),
+			Err(ParsingError::InvalidHex(String::from("ffff80000000000g")))
+		);
+	}
+
+	#[test]
+	fn from_inkscape_hex_rejects_invalid() {
+		assert_eq!(
+			from_inkscape_hex("11ff0a"),
+			Err(ParsingError::InvalidHex(String::from("11ff0a")))
+		);
+		assert_eq!(
+			from_inkscape_hex("11ff0agg"),
+			Err(ParsingError::InvalidHex(String::from("11ff0agg")))
+		);
+	}
+
+	#[test]
+	fn parse_color_strict_accepts_values_in_range() {
+		for seq in [
+			"rgb(255 0 51 / 50%)",
+			"rgba(255, 0, 51, 0.5)",
+			"hsl(180deg 50% 75%)",
+			"red",
+		] {
+			assert_eq!(parse_color_strict(seq), parse_color(seq), "{}", seq);
+		}
+	}
+
+	#[test]
+	fn parse_color_strict_rejects_values_out_of_range() {
+		for seq in [
+			"rgb(300 0 0)",
+			"rgb(0 -1 0)",
+			"rgb(0% 0% 101%)",
+			"rgba(0, 0, 0, 1.5)",
+			"hsl(180deg 120% 50%)",
+			"hwb(180deg 20% 30% / -10%)",
+		] {
+			assert!(
+				matches!(parse_color_strict(seq), Err(ParsingError::InvalidSyntax(_))),
+				"{}",
+				seq
+			);
+		}
+	}
+
+	#[test]
+	fn parse_color_unclamped_keeps_out_of_gamut_colors() {
+		let seq = "oklch(0.7 0.4 140)";
+
+		assert!(!is_in_srgb_gamut(&parse_color_unclamped(seq).unwrap()));
+		assert!(is_in_srgb_gamut(&parse_color(seq).unwrap()));
+	}
+
+	#[test]
+	fn split_outside_parentheses_keeps_empty_parts() {
+		assert_eq!(
+			split_outside_parentheses("a(b, c),, d", |c| c == ','),
+			vec!["a(b, c)", "", " d"]
+		);
+		assert_eq!(split_outside_parentheses("", |c| c == ','), vec![""]);
+	}
+
+	#[test]
+	fn split_color_list_respects_parentheses() {
+		assert_eq!(
+			split_color_list(" #FFF,rgb(255, 0, 0)  red ,, hsl(0 100% 50%)"),
+			vec!["#FFF", "rgb(255, 0, 0)", "red", "hsl(0 100% 50%)"]
+		);
+		assert!(split_color_list(" , ").is_empty());
+	}
+
+	#[test]
+	fn parse_multiple_colors_returns_all_results() {
+		let results = parse_multiple_colors("#F00, invalid rgb(0, 0, 255)");
+
+		assert_eq!(results.len(), 3);
+		assert_eq!(results[0], parse_color("#F00"));
+		assert!(results[1].is_err());
+		assert_eq!(results[2], parse_color("rgb(0, 0, 255)"));
+	}
+
+	#[test]
+	fn parse_function_channels_keeps_none() {
+		assert_eq!(
+			parse_function_channels("hsl(none 100% 50%)"),
+			Ok([None, Some(1.0), Some(0.5), Some(1.0)])
+		);
+		assert_eq!(
+			parse_function_channels("rgb(255 none 0 / 50%)"),
+			Ok([Some(1.0), None, Some(0.0), Some(0.5)])
+		);
+		assert!(parse_function_channels("lab(50 none 0)").is_err());
+	}
+}
@@ -0,0 +1,224 @@
+use std::sync::LazyLock;
+
+use palette::Srgba;
+use regex::{Captures, Regex};
+
+use crate::parser::css_types::{
+	parse_alpha_value, parse_number, parse_optional, parse_percentage, resolve_missing,
+	to_fraction, ParseMode, ALPHA_PATTERN, NUMBER_PATTERN,
+};
+use crate::parser::{FunctionChannels, ParsingError};
+
+static RGB_FUNCTION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"(?i)^rgba?\(\s*(?P<red>{NUMBER_PATTERN}%?|none)\s+(?P<green>{NUMBER_PATTERN}%?|none)\s+(?P<blue>{NUMBER_PATTERN}%?|none)\s*{ALPHA_PATTERN}\)$",
+	))
+	.unwrap()
+});
+
+static RGB_LEGACY_FUNCTION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+	Regex::new(&format!(
+		r"(?i)^rgba?\(\s*(?P<red>{NUMBER_PATTERN}%?)\s*,\s*(?P<green>{NUMBER_PATTERN}%?)\s*,\s*(?P<blue>{NUMBER_PATTERN}%?)\s*(?:,\s*(?P<alpha>{NUMBER_PATTERN}%?)\s*)?\)$",
+	))
+	.unwrap()
+});
+
+fn parse_color_channel(seq: &str, mode: ParseMode) -> Result<f32, ParsingError> {
+	match seq.strip_suffix('%') {
+		Some(percentage) => parse_percentage(percentage, mode),
+		None => to_fraction(parse_number(seq) / 255.0, seq, mode),
+	}
+}
+
+fn parse_color_channels(
+	captures: &Captures,
+	mode: ParseMode,
+) -> Result<FunctionChannels, ParsingError> {
+	let parse_channel =
+		|name: &str| parse_optional(&captures[name], |seq| parse_color_channel(seq, mode));
+	Ok([
+		parse_channel("red")?,
+		parse_channel("green")?,
+		parse_channel("blue")?,
+		parse_alpha_value(captures, mode)?,
+	])
+}
+
+/// Parses the channels of an RGB function string in either syntax as fractions,
+/// handling values out of range according to `mode`.
+pub(crate) fn parse_rgb_function_channels(
+	seq: &str,
+	mode: ParseMode,
+) -> Result<FunctionChannels, ParsingError> {
+	let seq = seq.trim();
+	if RGB_FUNCTION_REGEX.is_match(seq) {
+		parse_rgb_modern_function_channels(seq, mode)
+	} else if RGB_LEGACY_FUNCTION_REGEX.is_match(seq) {
+		parse_rgb_legacy_function_channels(seq, mode)
+	} else {
+		Err(ParsingError::InvalidSyntax(
+			"Expected RGB function in the form 'rgb(R G B / A)' or 'rgba(R, G, B, A)'.".into(),
+		))
+	}
+}
+
+/// Parses an RGB function string in either syntax, handling values out of range according to `mode`.
+pub(crate) fn parse_rgb_function(seq: &str, mode: ParseMode) -> Result<Srgba, ParsingError> {
+	parse_rgb_function_channels(seq, mode).map(|channels| Srgba::from(resolve_missing(channels)))
+}
+
+fn parse_rgb_modern_function_channels(
+	seq: &str,
+	mode: ParseMode,
+) -> Result<FunctionChannels, ParsingError> {
+	let captures = RGB_FUNCTION_REGEX.captures(seq.trim()).ok_or_else(|| {
+		ParsingError::InvalidSyntax(
+			"Expected RGB function in the form 'rgb(R G B)' or 'rgb(R G B / A)'.".into(),
+		)
+	})?;
+
+	parse_color_channels(&captures, mode)
+}
+
+fn parse_rgb_legacy_function_channels(
+	seq: &str,
+	mode: ParseMode,
+) -> Result<FunctionChannels, ParsingError> {
+	let captures = RGB_LEGACY_FUNCTION_REGEX
+		.captures(seq.trim())
+		.ok_or_else(|| {
+			ParsingError::InvalidSyntax(
+				"Expected RGB function in the form 'rgb(R, G, B)' or 'rgba(R, G, B, A)'.".into(),
+			)
+		})?;
+
+	let percentage_channel_count = ["red", "green", "blue"]
+		.iter()
+		.filter(|name| captures[**name].ends_with('%'))
+		.count();
+	if percentage_channel_count != 0 && percentage_channel_count != 3 {
+		return Err(ParsingError::InvalidSyntax(
+			"Legacy RGB function must not mix numbers and percentages.".into(),
+		));
+	}
+
+	parse_color_channels(&captures, mode)
+}
+
+/// Parses a CSS-style RGB function string in either the modern (e.g. `rgb(255 128 0 / 0.5)`)
+/// or the legacy (e.g. `rgba(255, 128, 0, 0.5)`) syntax.
+/// Values out of range are clamped, channels given as `none` are treated as `0`.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#rgb-functions).
+///
+/// # Errors
+/// - If the string is not a valid RGB function in either syntax.
+pub fn from_rgb_function_str(seq: &str) -> Result<Srgba, ParsingError> {
+	from_rgb_modern_function_str(seq)
+		.or_else(|_| from_rgb_legacy_function_str(seq))
+		.map_err(|_| {
+			ParsingError::InvalidSyntax(
+				"Expected RGB function in the form 'rgb(R G B / A)' or 'rgba(R, G, B, A)'.".into(),
+			)
+		})
+}
+
+/// Parses a CSS-style RGB function string in the modern space-separated syntax,
+/// e.g. `rgb(255 128 0 / 0.5)`.
+/// Values out of range are clamped, channels given as `none` are treated as `0`.
+///
+/// # Errors
+/// - If the string is not a valid RGB function in the modern syntax.
+pub fn from_rgb_modern_function_str(seq: &str) -> Result<Srgba, ParsingError> {
+	parse_rgb_modern_function_channels(seq, ParseMode::Clamp)
+		.map(|channels| Srgba::from(resolve_missing(channels)))
+}
+
+/// Parses a CSS-style RGB function string in the legacy comma-separated syntax,
+/// e.g. `rgba(255, 128, 0, 0.5)`.
+/// Values out of range are clamped.
+///
+/// # Errors
+/// - If the string is not a valid RGB function in the legacy syntax.
+/// - If numbers and percentages are mixed for the color channels, which the legacy syntax does not allow.
+pub fn from_rgb_legacy_function_str(seq: &str) -> Result<Srgba, ParsingError> {
+	parse_rgb_legacy_function_channels(seq, ParseMode::Clamp)
+		.map(|channels| Srgba::from(resolve_missing(channels)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_rgb_modern_function_str_parses() {
+		assert_eq!(
+			from_rgb_modern_function_str("rgb(255 0 51)"),
+			Ok(Srgba::new(1.0, 0.0, 0.2, 1.0))
+		);
+		assert_eq!(
+			from_rgb_modern_function_str("RGB(100% 0% 20% / 50%)"),
+			Ok(Srgba::new(1.0, 0.0, 0.2, 0.5))
+		);
+		assert!(from_rgb_modern_function_str("rgb(255, 0, 51)").is_err());
+	}
+
+	#[test]
+	fn from_rgb_modern_function_str_parses_none() {
+		assert_eq!(
+			from_rgb_modern_function_str("rgb(none 255 NONE / none)"),
+			Ok(Srgba::new(0.0, 1.0, 0.0, 0.0))
+		);
+		assert_eq!(
+			parse_rgb_function_channels("rgb(none 255 0)", ParseMode::Clamp),
+			Ok([None, Some(1.0), Some(0.0), Some(1.0)])
+		);
+		assert!(from_rgb_legacy_function_str("rgb(none, 255, 0)").is_err());
+	}
+
+	#[test]
+	fn from_rgb_legacy_function_str_parses() {
+		assert_eq!(
+			from_rgb_legacy_function_str("rgba(255, 0, 51, 0.5)"),
+			Ok(Srgba::new(1.0, 0.0, 0.2, 0.5))
+		);
+		assert_eq!(
+			from_rgb_legacy_function_str("rgb(100%,0%,20%)"),
+			Ok(Srgba::new(1.0, 0.0, 0.2, 1.0))
+		);
+		assert!(from_rgb_legacy_function_str("rgb(255 0 51)").is_err());
+	}
+
+	#[test]
+	fn from_rgb_legacy_function_str_rejects_mixed_units() {
+		assert!(matches!(
+			from_rgb_legacy_function_str("rgb(255, 0%, 51)"),
+			Err(ParsingError::InvalidSyntax(_))
+		));
+	}
+
+	#[test]
+	fn from_rgb_function_str_parses_both_syntaxes() {
+		assert_eq!(
+			from_rgb_function_str("rgb(255 0 51 / 0.5)"),
+			Ok(Srgba::new(1.0, 0.0, 0.2, 0.5))
+		);
+		assert_eq!(
+			from_rgb_function_str("rgba(255, 0, 51, 0.5)"),
+			Ok(Srgba::new(1.0, 0.0, 0.2, 0.5))
+		);
+	}
+
+	#[test]
+	fn from_rgb_function_str_clamps() {
+		assert_eq!(
+			from_rgb_function_str("rgb(300 -10 0 / 2)"),
+			Ok(Srgba::new(1.0, 0.0, 0.0, 1.0))
+		);
+	}
+
+	#[test]
+	fn from_rgb_function_str_rejects_invalid() {
+		assert!(from_rgb_function_str("rgb(255 0)").is_err());
+		assert!(from_rgb_function_str("hsl(255 0 51)").is_err());
+	}
+}
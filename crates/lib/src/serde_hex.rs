@@ -0,0 +1,68 @@
+//! Serialization of colors as CSS hex strings, for use with `#[serde(with = "color_utils::serde_hex")]`.
+//!
+//! Colors are serialized in uppercase hex notation without shorthand (e.g. `"#FF8000"`),
+//! with the alpha channel appended only if the color is not opaque (e.g. `"#FF800080"`).
+//! Channels are rounded to 8 bit.
+//! Deserialization accepts any color string supported by [`parse_color`].
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use palette::Srgba;
+
+use crate::parser::parse_color;
+use crate::to_str::{to_rgb_hex_str, LetterCase, OmitAlphaChannel, ShorthandNotation};
+
+pub fn serialize<S: Serializer>(color: &Srgba, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&to_rgb_hex_str(
+		&color.into_format(),
+		OmitAlphaChannel::IfOpaque,
+		ShorthandNotation::Never,
+		LetterCase::Uppercase,
+	))
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Srgba, D::Error> {
+	let seq = String::deserialize(deserializer)?;
+	parse_color(&seq).map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+	use serde::Serialize;
+
+	use super::*;
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Theme {
+		#[serde(with = "crate::serde_hex")]
+		color: Srgba,
+	}
+
+	#[test]
+	fn serialize_opaque() {
+		let theme = Theme {
+			color: Srgba::<u8>::new(0xff, 0x80, 0x00, 0xff).into_format(),
+		};
+
+		let json = serde_json::to_string(&theme).unwrap();
+		assert_eq!(json, r##"{"color":"#FF8000"}"##);
+		assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+	}
+
+	#[test]
+	fn serialize_non_opaque() {
+		let theme = Theme {
+			color: Srgba::<u8>::new(0xff, 0x80, 0x00, 0x80).into_format(),
+		};
+
+		let json = serde_json::to_string(&theme).unwrap();
+		assert_eq!(json, r##"{"color":"#FF800080"}"##);
+		assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+	}
+
+	#[test]
+	fn deserialize_invalid() {
+		assert!(serde_json::from_str::<Theme>(r#"{"color":"foo"}"#).is_err());
+	}
+}
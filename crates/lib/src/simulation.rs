@@ -0,0 +1,115 @@
+use palette::rgb::Rgb;
+use palette::{LinSrgb, Srgb};
+
+/// Types of color vision deficiency that can be simulated.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum ColorBlindnessMode {
+	/// Missing red (L) cones.
+	Protanopia,
+
+	/// Missing green (M) cones.
+	Deuteranopia,
+
+	/// Missing blue (S) cones.
+	Tritanopia,
+
+	/// Anomalous red (L) cones.
+	Protanomaly,
+
+	/// Anomalous green (M) cones.
+	Deuteranomaly,
+
+	/// Anomalous blue (S) cones.
+	Tritanomaly,
+}
+
+type Matrix = [[f32; 3]; 3];
+
+/// Simulation matrices for linear sRGB from <https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html>.
+/// They combine the conversion to LMS, the simulated cone response, and the conversion back into a single matrix.
+/// Anomalies use a severity of 0.6.
+fn simulation_matrix(mode: ColorBlindnessMode) -> Matrix {
+	match mode {
+		ColorBlindnessMode::Protanopia => [
+			[0.152286, 1.052583, -0.204868],
+			[0.114503, 0.786281, 0.099216],
+			[-0.003882, -0.048116, 1.051998],
+		],
+		ColorBlindnessMode::Deuteranopia => [
+			[0.367322, 0.860646, -0.227968],
+			[0.280085, 0.672501, 0.047413],
+			[-0.011820, 0.042940, 0.968881],
+		],
+		ColorBlindnessMode::Tritanopia => [
+			[1.255528, -0.076749, -0.178779],
+			[-0.078411, 0.930809, 0.147602],
+			[0.004733, 0.691367, 0.303900],
+		],
+		ColorBlindnessMode::Protanomaly => [
+			[0.385450, 0.769005, -0.154455],
+			[0.100526, 0.829802, 0.069673],
+			[-0.007442, -0.022190, 1.029632],
+		],
+		ColorBlindnessMode::Deuteranomaly => [
+			[0.547494, 0.607765, -0.155259],
+			[0.181692, 0.781742, 0.036566],
+			[-0.010410, 0.027275, 0.983136],
+		],
+		ColorBlindnessMode::Tritanomaly => [
+			[1.104996, -0.046633, -0.058363],
+			[-0.032137, 0.971635, 0.060503],
+			[0.001336, 0.317922, 0.680742],
+		],
+	}
+}
+
+/// Simulates how the color is perceived with the given color vision deficiency,
+/// using the model by Machado et al. in linear light.
+pub fn simulate_color_blindness(color: &Rgb, mode: ColorBlindnessMode) -> Rgb {
+	let matrix = simulation_matrix(mode);
+	let linear: LinSrgb = color.into_linear();
+	let channels = [linear.red, linear.green, linear.blue];
+
+	let [red, green, blue] = matrix.map(|row| {
+		(row[0] * channels[0] + row[1] * channels[1] + row[2] * channels[2]).clamp(0.0, 1.0)
+	});
+	Srgb::from_linear(LinSrgb::new(red, green, blue))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const MODES: [ColorBlindnessMode; 6] = [
+		ColorBlindnessMode::Protanopia,
+		ColorBlindnessMode::Deuteranopia,
+		ColorBlindnessMode::Tritanopia,
+		ColorBlindnessMode::Protanomaly,
+		ColorBlindnessMode::Deuteranomaly,
+		ColorBlindnessMode::Tritanomaly,
+	];
+
+	#[test]
+	fn simulate_color_blindness_keeps_grays() {
+		let gray = Rgb::new(0.5, 0.5, 0.5);
+
+		for mode in MODES {
+			assert_eq!(
+				simulate_color_blindness(&gray, mode).into_format::<u8>(),
+				gray.into_format(),
+				"{:?}",
+				mode
+			);
+		}
+	}
+
+	#[test]
+	fn simulate_color_blindness_protanopia() {
+		let red = Rgb::new(1.0, 0.0, 0.0);
+
+		assert_eq!(
+			simulate_color_blindness(&red, ColorBlindnessMode::Protanopia).into_format::<u8>(),
+			Rgb::<_, u8>::new(109, 95, 0)
+		);
+	}
+}
@@ -2,7 +2,6 @@ use palette::Srgba;
 
 use crate::to_str::css_types::format_alpha_value;
 use crate::to_str::{ChannelUnit, OmitAlphaChannel};
-use crate::util::is_opaque;
 
 // TODO: allow any `Alpha` type
 /// Wrapper to handle common handling of alpha omission
@@ -10,10 +9,15 @@ pub(crate) fn format_alpha_value_conditionally(
 	color: &Srgba,
 	alpha_channel_unit: ChannelUnit,
 	omit_alpha_channel: OmitAlphaChannel,
+	precision: u8,
 ) -> Option<String> {
-	if omit_alpha_channel == OmitAlphaChannel::IfOpaque && is_opaque(color) {
+	if omit_alpha_channel.omits(color) {
 		None
 	} else {
-		Some(format_alpha_value(color.alpha, alpha_channel_unit))
+		Some(format_alpha_value(
+			color.alpha,
+			alpha_channel_unit,
+			precision,
+		))
 	}
 }
@@ -1,31 +1,34 @@
-use palette::RgbHue;
-
-use crate::to_str::ChannelUnit;
+use crate::to_str::{ChannelUnit, MAX_DECIMAL_PRECISION};
 
 // used over default string formatting to only use decimal places if needed.
-fn ceil_two_decimal_places(val: f32) -> f32 {
-	(val * 100.0).ceil() / 100.0
+fn ceil_decimal_places(val: f32, precision: u8) -> f32 {
+	// Also covers `FULL_DECIMAL_PRECISION`, for which the factor would overflow.
+	if precision > MAX_DECIMAL_PRECISION {
+		return val;
+	}
+	let factor = 10f32.powi(i32::from(precision));
+	(val * factor).ceil() / factor
 }
 
-/// Formats a float as a CSS number (e.g., `0.6` as `'0.6'`).
-pub(crate) fn format_number(val: f32) -> String {
-	format!("{}", ceil_two_decimal_places(val))
+/// Formats a float as a CSS number with at most `precision` decimal places (e.g., `0.6` as `'0.6'`).
+pub(crate) fn format_number(val: f32, precision: u8) -> String {
+	format!("{}", ceil_decimal_places(val, precision))
 }
 
-/// Formats a float as a CSS percentage (e.g., `0.6` as `'60%'`).
-pub(crate) fn format_percentage(val: f32) -> String {
-	format!("{}%", ceil_two_decimal_places(val * 100.0))
+/// Formats a float as a CSS percentage with at most `precision` decimal places (e.g., `0.6` as `'60%'`).
+pub(crate) fn format_percentage(val: f32, precision: u8) -> String {
+	format!("{}%", ceil_decimal_places(val * 100.0, precision))
 }
 
 /// Formats a float as an alpha-value.
-pub(crate) fn format_alpha_value(alpha: f32, unit: ChannelUnit) -> String {
+pub(crate) fn format_alpha_value(alpha: f32, unit: ChannelUnit, precision: u8) -> String {
 	match unit {
-		ChannelUnit::Number => format_number(alpha),
-		ChannelUnit::Percentage => format_percentage(alpha),
+		ChannelUnit::Number => format_number(alpha, precision),
+		ChannelUnit::Percentage => format_percentage(alpha, precision),
 	}
 }
 
 /// Formats a hue as degrees.
-pub(crate) fn format_hue(hue: RgbHue) -> String {
-	format!("{}deg", format_number(hue.into_positive_degrees()))
+pub(crate) fn format_hue(degrees: f32, precision: u8) -> String {
+	format!("{}deg", format_number(degrees, precision))
 }
@@ -0,0 +1,75 @@
+use palette::{Srgba, WithAlpha};
+
+use crate::display_p3::srgb_to_display_p3;
+use crate::to_str::common::format_alpha_value_conditionally;
+use crate::to_str::css_types::format_number;
+use crate::to_str::{ChannelUnit, OmitAlphaChannel, DEFAULT_DECIMAL_PRECISION};
+
+/// Creates a CSS-style `color()` function string for this color in the Display P3 color space,
+/// e.g. `color(display-p3 0.5 0.25 0.75)`.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#predefined-display-p3).
+pub fn to_display_p3_function_str(
+	color: &Srgba,
+	omit_alpha_channel: OmitAlphaChannel,
+	alpha_channel_unit: ChannelUnit,
+) -> String {
+	to_display_p3_function_str_with_precision(
+		color,
+		omit_alpha_channel,
+		alpha_channel_unit,
+		DEFAULT_DECIMAL_PRECISION,
+	)
+}
+
+/// Like [`to_display_p3_function_str`], but with at most `precision` decimal places for each value.
+pub fn to_display_p3_function_str_with_precision(
+	color: &Srgba,
+	omit_alpha_channel: OmitAlphaChannel,
+	alpha_channel_unit: ChannelUnit,
+	precision: u8,
+) -> String {
+	let display_p3 = srgb_to_display_p3(&color.without_alpha());
+	let red_str = format_number(display_p3.red, precision);
+	let green_str = format_number(display_p3.green, precision);
+	let blue_str = format_number(display_p3.blue, precision);
+	let alpha_str_opt =
+		format_alpha_value_conditionally(color, alpha_channel_unit, omit_alpha_channel, precision);
+
+	alpha_str_opt.map_or_else(
+		|| {
+			format!(
+				"color(display-p3 {} {} {})",
+				&red_str, &green_str, &blue_str
+			)
+		},
+		|alpha_str| {
+			format!(
+				"color(display-p3 {} {} {} / {})",
+				&red_str, &green_str, &blue_str, &alpha_str
+			)
+		},
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_display_p3_function_str_omit_alpha_channel_opaque() {
+		let color = Srgba::new(0.0, 0.0, 0.0, 1.0);
+
+		let result =
+			to_display_p3_function_str(&color, OmitAlphaChannel::IfOpaque, ChannelUnit::Number);
+		assert_eq!(result, "color(display-p3 0 0 0)");
+	}
+
+	#[test]
+	fn to_display_p3_function_str_converts_red() {
+		let color = Srgba::new(1.0, 0.0, 0.0, 0.5);
+
+		let result =
+			to_display_p3_function_str(&color, OmitAlphaChannel::IfOpaque, ChannelUnit::Percentage);
+		assert_eq!(result, "color(display-p3 0.92 0.21 0.14 / 50%)");
+	}
+}
@@ -2,7 +2,7 @@ use palette::{Hsla, IntoColor};
 
 use crate::to_str::common::format_alpha_value_conditionally;
 use crate::to_str::css_types::{format_hue, format_percentage};
-use crate::to_str::{ChannelUnit, OmitAlphaChannel};
+use crate::to_str::{ChannelUnit, OmitAlphaChannel, DEFAULT_DECIMAL_PRECISION};
 
 /// Creates a CSS-style HSL function string for this color.
 /// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#the-hsl-notation).
@@ -11,13 +11,29 @@ pub fn to_hsl_function_str(
 	omit_alpha_channel: OmitAlphaChannel,
 	alpha_channel_unit: ChannelUnit,
 ) -> String {
-	let hue_str = format_hue(color.hue);
-	let saturation_str = format_percentage(color.saturation);
-	let lightness_str = format_percentage(color.lightness);
+	to_hsl_function_str_with_precision(
+		color,
+		omit_alpha_channel,
+		alpha_channel_unit,
+		DEFAULT_DECIMAL_PRECISION,
+	)
+}
+
+/// Like [`to_hsl_function_str`], but with at most `precision` decimal places for each value.
+pub fn to_hsl_function_str_with_precision(
+	color: &Hsla,
+	omit_alpha_channel: OmitAlphaChannel,
+	alpha_channel_unit: ChannelUnit,
+	precision: u8,
+) -> String {
+	let hue_str = format_hue(color.hue.into_positive_degrees(), precision);
+	let saturation_str = format_percentage(color.saturation, precision);
+	let lightness_str = format_percentage(color.lightness, precision);
 	let alpha_str_opt = format_alpha_value_conditionally(
 		&(*color).into_color(),
 		alpha_channel_unit,
 		omit_alpha_channel,
+		precision,
 	);
 
 	alpha_str_opt.map_or_else(
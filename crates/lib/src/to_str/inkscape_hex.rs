@@ -0,0 +1,37 @@
+use palette::Srgba;
+
+use crate::to_str::{to_rgb_hex_str, LetterCase, OmitAlphaChannel, ShorthandNotation};
+
+/// Creates an Inkscape-style hex color string for this color.
+/// Unlike the CSS hex notation, this always consists of 8 digits in the order `RRGGBBAA`
+/// without leading `#`, even if the color is opaque.
+pub fn to_inkscape_hex(color: &Srgba<u8>, letter_case: LetterCase) -> String {
+	let hex_str = to_rgb_hex_str(
+		color,
+		OmitAlphaChannel::Never,
+		ShorthandNotation::Never,
+		letter_case,
+	);
+	String::from(&hex_str[1..])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_inkscape_hex_opaque() {
+		let color = Srgba::<u8>::new(0x11, 0xff, 0x0a, 0xff);
+
+		let result = to_inkscape_hex(&color, LetterCase::Lowercase);
+		assert_eq!(result, "11ff0aff");
+	}
+
+	#[test]
+	fn to_inkscape_hex_transparent() {
+		let color = Srgba::<u8>::new(0x11, 0xff, 0x0a, 0x00);
+
+		let result = to_inkscape_hex(&color, LetterCase::Uppercase);
+		assert_eq!(result, "11FF0A00");
+	}
+}
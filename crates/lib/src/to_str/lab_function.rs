@@ -0,0 +1,88 @@
+use palette::{IntoColor, Laba};
+
+use crate::to_str::common::format_alpha_value_conditionally;
+use crate::to_str::css_types::format_number;
+use crate::to_str::{ChannelUnit, OmitAlphaChannel, DEFAULT_DECIMAL_PRECISION};
+
+/// Creates a CSS-style Lab function string for this color.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#specifying-lab-lch).
+pub fn to_lab_function_str(
+	color: &Laba,
+	omit_alpha_channel: OmitAlphaChannel,
+	alpha_channel_unit: ChannelUnit,
+) -> String {
+	to_lab_function_str_with_precision(
+		color,
+		omit_alpha_channel,
+		alpha_channel_unit,
+		DEFAULT_DECIMAL_PRECISION,
+	)
+}
+
+/// Like [`to_lab_function_str`], but with at most `precision` decimal places for each value.
+pub fn to_lab_function_str_with_precision(
+	color: &Laba,
+	omit_alpha_channel: OmitAlphaChannel,
+	alpha_channel_unit: ChannelUnit,
+	precision: u8,
+) -> String {
+	// Lightness is already in the range 0 to 100.
+	let lightness_str = format!("{}%", format_number(color.l, precision));
+	let a_str = format_number(color.a, precision);
+	let b_str = format_number(color.b, precision);
+	let alpha_str_opt = format_alpha_value_conditionally(
+		&(*color).into_color(),
+		alpha_channel_unit,
+		omit_alpha_channel,
+		precision,
+	);
+
+	alpha_str_opt.map_or_else(
+		|| format!("lab({} {} {})", &lightness_str, &a_str, &b_str),
+		|alpha_str| {
+			format!(
+				"lab({} {} {} / {})",
+				&lightness_str, &a_str, &b_str, &alpha_str
+			)
+		},
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_lab_function_str_omit_alpha_channel_opaque() {
+		let color: Laba = Laba::new(50.0, 25.0, -10.0, 1.0);
+
+		let result =
+			to_lab_function_str(&color, OmitAlphaChannel::IfOpaque, ChannelUnit::Percentage);
+		assert_eq!(result, "lab(50% 25 -10)");
+	}
+
+	#[test]
+	fn to_lab_function_str_omit_alpha_channel_non_opaque() {
+		let color: Laba = Laba::new(50.0, 25.0, -10.0, 0.8);
+
+		let result =
+			to_lab_function_str(&color, OmitAlphaChannel::IfOpaque, ChannelUnit::Percentage);
+		assert_eq!(result, "lab(50% 25 -10 / 80%)");
+	}
+
+	#[test]
+	fn to_lab_function_str_omit_alpha_never() {
+		let color: Laba = Laba::new(50.0, 25.0, -10.0, 1.0);
+
+		let result = to_lab_function_str(&color, OmitAlphaChannel::Never, ChannelUnit::Percentage);
+		assert_eq!(result, "lab(50% 25 -10 / 100%)");
+	}
+
+	#[test]
+	fn to_lab_function_str_number_alpha_channel() {
+		let color: Laba = Laba::new(50.0, 25.0, -10.0, 1.0);
+
+		let result = to_lab_function_str(&color, OmitAlphaChannel::Never, ChannelUnit::Number);
+		assert_eq!(result, "lab(50% 25 -10 / 1)");
+	}
+}
@@ -0,0 +1,88 @@
+use palette::{IntoColor, Lcha};
+
+use crate::to_str::common::format_alpha_value_conditionally;
+use crate::to_str::css_types::{format_hue, format_number};
+use crate::to_str::{ChannelUnit, OmitAlphaChannel, DEFAULT_DECIMAL_PRECISION};
+
+/// Creates a CSS-style LCH function string for this color.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#specifying-lab-lch).
+pub fn to_lch_function_str(
+	color: &Lcha,
+	omit_alpha_channel: OmitAlphaChannel,
+	alpha_channel_unit: ChannelUnit,
+) -> String {
+	to_lch_function_str_with_precision(
+		color,
+		omit_alpha_channel,
+		alpha_channel_unit,
+		DEFAULT_DECIMAL_PRECISION,
+	)
+}
+
+/// Like [`to_lch_function_str`], but with at most `precision` decimal places for each value.
+pub fn to_lch_function_str_with_precision(
+	color: &Lcha,
+	omit_alpha_channel: OmitAlphaChannel,
+	alpha_channel_unit: ChannelUnit,
+	precision: u8,
+) -> String {
+	// Lightness is already in the range 0 to 100.
+	let lightness_str = format!("{}%", format_number(color.l, precision));
+	let chroma_str = format_number(color.chroma, precision);
+	let hue_str = format_hue(color.hue.into_positive_degrees(), precision);
+	let alpha_str_opt = format_alpha_value_conditionally(
+		&(*color).into_color(),
+		alpha_channel_unit,
+		omit_alpha_channel,
+		precision,
+	);
+
+	alpha_str_opt.map_or_else(
+		|| format!("lch({} {} {})", &lightness_str, &chroma_str, &hue_str),
+		|alpha_str| {
+			format!(
+				"lch({} {} {} / {})",
+				&lightness_str, &chroma_str, &hue_str, &alpha_str
+			)
+		},
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_lch_function_str_omit_alpha_channel_opaque() {
+		let color: Lcha = Lcha::new(60.0, 45.0, 250.0, 1.0);
+
+		let result =
+			to_lch_function_str(&color, OmitAlphaChannel::IfOpaque, ChannelUnit::Percentage);
+		assert_eq!(result, "lch(60% 45 250deg)");
+	}
+
+	#[test]
+	fn to_lch_function_str_omit_alpha_channel_non_opaque() {
+		let color: Lcha = Lcha::new(60.0, 45.0, 250.0, 0.5);
+
+		let result =
+			to_lch_function_str(&color, OmitAlphaChannel::IfOpaque, ChannelUnit::Percentage);
+		assert_eq!(result, "lch(60% 45 250deg / 50%)");
+	}
+
+	#[test]
+	fn to_lch_function_str_omit_alpha_never() {
+		let color: Lcha = Lcha::new(60.0, 45.0, 250.0, 1.0);
+
+		let result = to_lch_function_str(&color, OmitAlphaChannel::Never, ChannelUnit::Percentage);
+		assert_eq!(result, "lch(60% 45 250deg / 100%)");
+	}
+
+	#[test]
+	fn to_lch_function_str_number_alpha_channel() {
+		let color: Lcha = Lcha::new(60.0, 45.0, 250.0, 1.0);
+
+		let result = to_lch_function_str(&color, OmitAlphaChannel::Never, ChannelUnit::Number);
+		assert_eq!(result, "lch(60% 45 250deg / 1)");
+	}
+}
@@ -1,20 +1,70 @@
-pub use crate::to_str::hsl_function::to_hsl_function_str;
-pub use crate::to_str::hwb_function::to_hwb_function_str;
-pub use crate::to_str::rgb_function::to_rgb_function_str;
-pub use crate::to_str::rgb_hex::{to_rgb_hex_str, LetterCase, ShorthandNotation};
+pub use crate::to_str::display_p3_function::{
+	to_display_p3_function_str, to_display_p3_function_str_with_precision,
+};
+pub use crate::to_str::hsl_function::{to_hsl_function_str, to_hsl_function_str_with_precision};
+pub use crate::to_str::hwb_function::{to_hwb_function_str, to_hwb_function_str_with_precision};
+pub use crate::to_str::inkscape_hex::to_inkscape_hex;
+pub use crate::to_str::lab_function::{to_lab_function_str, to_lab_function_str_with_precision};
+pub use crate::to_str::lch_function::{to_lch_function_str, to_lch_function_str_with_precision};
+pub use crate::to_str::oklab_function::{
+	to_oklab_function_str, to_oklab_function_str_with_precision,
+};
+pub use crate::to_str::oklch_function::{
+	to_oklch_function_str, to_oklch_function_str_with_precision,
+};
+pub use crate::to_str::rgb_function::{
+	to_rgb_function_str, to_rgb_function_str_with_precision, to_rgb_legacy_function_str,
+	to_rgb_legacy_function_str_with_precision,
+};
+pub use crate::to_str::rgb_hex::{to_rgb_hex_str, LetterCase, RgbHex, ShorthandNotation};
+
+use palette::{IntoColor, Srgba};
+
+use crate::util::{is_effectively_opaque, OPAQUE_TOLERANCE};
 
 mod common;
 mod css_types;
+mod display_p3_function;
 mod hsl_function;
 mod hwb_function;
+mod inkscape_hex;
+mod lab_function;
+mod lch_function;
+mod oklab_function;
+mod oklch_function;
 mod rgb_function;
 mod rgb_hex;
 
+/// Maximum number of decimal places used by the CSS function serializers unless specified otherwise.
+/// Values are rounded up and trailing zeros are omitted.
+pub const DEFAULT_DECIMAL_PRECISION: u8 = 2;
+
+/// Precision to use for formatting values without rounding, e.g. for debugging.
+pub const FULL_DECIMAL_PRECISION: u8 = u8::MAX;
+
+/// Maximum number of decimal places values are rounded to, as `f32` has only about 7 significant digits.
+/// Larger precisions are treated like [`FULL_DECIMAL_PRECISION`].
+pub const MAX_DECIMAL_PRECISION: u8 = 6;
+
 /// If the alpha channel may be omitted if it is opaque.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum OmitAlphaChannel {
 	Never,
+	/// Omits the alpha channel if it is 1.0, ignoring floating point errors, see [`OPAQUE_TOLERANCE`].
 	IfOpaque,
+	/// Omits the alpha channel if it rounds to 255 in 8 bit, e.g. for `0.9999999`.
+	IfRoundsToOpaque,
+}
+
+impl OmitAlphaChannel {
+	/// Checks if the alpha channel of the color should be omitted.
+	pub(crate) fn omits(&self, color: &Srgba) -> bool {
+		match self {
+			OmitAlphaChannel::Never => false,
+			OmitAlphaChannel::IfOpaque => is_effectively_opaque(color, OPAQUE_TOLERANCE),
+			OmitAlphaChannel::IfRoundsToOpaque => (color.alpha * 255.0).round() >= 255.0,
+		}
+	}
 }
 
 /// Possible CSS types able to represent an RGB component value.
@@ -23,3 +73,190 @@ pub enum ChannelUnit {
 	Number,
 	Percentage,
 }
+
+/// CSS color formats a color can be serialized to with [`to_css_string`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CssColorFormat {
+	Hex,
+	RgbFunction,
+	HslFunction,
+	HwbFunction,
+	LabFunction,
+	OklabFunction,
+	LchFunction,
+	OklchFunction,
+	DisplayP3Function,
+}
+
+/// Creates a CSS color string for this color in the given format.
+/// Hex strings use the shorthand notation if possible and uppercase letters,
+/// all other formats use numbers rather than percentages where possible.
+///
+/// For more control over the output, use the function for the specific format, e.g. [`to_rgb_hex_str`].
+pub fn to_css_string(
+	color: &Srgba,
+	format: CssColorFormat,
+	omit_alpha_channel: OmitAlphaChannel,
+) -> String {
+	to_css_string_with_precision(color, format, omit_alpha_channel, DEFAULT_DECIMAL_PRECISION)
+}
+
+/// Like [`to_css_string`], but with at most `precision` decimal places for each value.
+/// Hex strings are not affected.
+pub fn to_css_string_with_precision(
+	color: &Srgba,
+	format: CssColorFormat,
+	omit_alpha_channel: OmitAlphaChannel,
+	precision: u8,
+) -> String {
+	match format {
+		CssColorFormat::Hex => to_rgb_hex_str(
+			&color.into_format(),
+			omit_alpha_channel,
+			ShorthandNotation::IfPossible,
+			LetterCase::Uppercase,
+		),
+		CssColorFormat::RgbFunction => to_rgb_function_str_with_precision(
+			color,
+			omit_alpha_channel,
+			ChannelUnit::Number,
+			ChannelUnit::Number,
+			precision,
+		),
+		CssColorFormat::HslFunction => to_hsl_function_str_with_precision(
+			&(*color).into_color(),
+			omit_alpha_channel,
+			ChannelUnit::Number,
+			precision,
+		),
+		CssColorFormat::HwbFunction => to_hwb_function_str_with_precision(
+			&(*color).into_color(),
+			omit_alpha_channel,
+			ChannelUnit::Number,
+			precision,
+		),
+		CssColorFormat::LabFunction => to_lab_function_str_with_precision(
+			&(*color).into_color(),
+			omit_alpha_channel,
+			ChannelUnit::Number,
+			precision,
+		),
+		CssColorFormat::OklabFunction => to_oklab_function_str_with_precision(
+			&(*color).into_color(),
+			omit_alpha_channel,
+			ChannelUnit::Number,
+			precision,
+		),
+		CssColorFormat::LchFunction => to_lch_function_str_with_precision(
+			&(*color).into_color(),
+			omit_alpha_channel,
+			ChannelUnit::Number,
+			precision,
+		),
+		CssColorFormat::OklchFunction => to_oklch_function_str_with_precision(
+			&(*color).into_color(),
+			omit_alpha_channel,
+			ChannelUnit::Number,
+			precision,
+		),
+		CssColorFormat::DisplayP3Function => to_display_p3_function_str_with_precision(
+			color,
+			omit_alpha_channel,
+			ChannelUnit::Number,
+			precision,
+		),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_css_string_dispatches() {
+		let color: Srgba = Srgba::<u8>::new(255, 0, 0, 255).into_format();
+
+		assert_eq!(
+			to_css_string(&color, CssColorFormat::Hex, OmitAlphaChannel::IfOpaque),
+			"#F00"
+		);
+		assert_eq!(
+			to_css_string(
+				&color,
+				CssColorFormat::RgbFunction,
+				OmitAlphaChannel::IfOpaque
+			),
+			"rgb(255 0 0)"
+		);
+		assert_eq!(
+			to_css_string(
+				&color,
+				CssColorFormat::HslFunction,
+				OmitAlphaChannel::IfOpaque
+			),
+			to_hsl_function_str(
+				&color.into_color(),
+				OmitAlphaChannel::IfOpaque,
+				ChannelUnit::Number
+			)
+		);
+	}
+
+	#[test]
+	fn to_css_string_with_precision_rounds() {
+		let color = Srgba::new(0.5, 0.0, 0.0, 1.0);
+
+		assert_eq!(
+			to_css_string_with_precision(
+				&color,
+				CssColorFormat::RgbFunction,
+				OmitAlphaChannel::IfOpaque,
+				0
+			),
+			"rgb(128 0 0)"
+		);
+		assert_eq!(
+			to_css_string_with_precision(
+				&color,
+				CssColorFormat::RgbFunction,
+				OmitAlphaChannel::IfOpaque,
+				FULL_DECIMAL_PRECISION
+			),
+			"rgb(127.5 0 0)"
+		);
+	}
+
+	#[test]
+	fn to_css_string_with_precision_large_precision_is_full() {
+		let color = Srgba::new(0.5, 0.0, 0.0, 1.0);
+
+		assert_eq!(
+			to_css_string_with_precision(
+				&color,
+				CssColorFormat::RgbFunction,
+				OmitAlphaChannel::IfOpaque,
+				MAX_DECIMAL_PRECISION + 1
+			),
+			"rgb(127.5 0 0)"
+		);
+		assert_eq!(
+			to_css_string_with_precision(
+				&color,
+				CssColorFormat::RgbFunction,
+				OmitAlphaChannel::IfOpaque,
+				40
+			),
+			"rgb(127.5 0 0)"
+		);
+	}
+
+	#[test]
+	fn to_css_string_omit_alpha_channel() {
+		let color: Srgba = Srgba::<u8>::new(255, 0, 0, 255).into_format();
+
+		assert_eq!(
+			to_css_string(&color, CssColorFormat::Hex, OmitAlphaChannel::Never),
+			"#F00F"
+		);
+	}
+}
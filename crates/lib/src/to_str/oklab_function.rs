@@ -0,0 +1,88 @@
+use palette::{IntoColor, Oklaba};
+
+use crate::to_str::common::format_alpha_value_conditionally;
+use crate::to_str::css_types::format_number;
+use crate::to_str::{ChannelUnit, OmitAlphaChannel, DEFAULT_DECIMAL_PRECISION};
+
+/// Creates a CSS-style Oklab function string for this color.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch).
+pub fn to_oklab_function_str(
+	color: &Oklaba,
+	omit_alpha_channel: OmitAlphaChannel,
+	alpha_channel_unit: ChannelUnit,
+) -> String {
+	to_oklab_function_str_with_precision(
+		color,
+		omit_alpha_channel,
+		alpha_channel_unit,
+		DEFAULT_DECIMAL_PRECISION,
+	)
+}
+
+/// Like [`to_oklab_function_str`], but with at most `precision` decimal places for each value.
+pub fn to_oklab_function_str_with_precision(
+	color: &Oklaba,
+	omit_alpha_channel: OmitAlphaChannel,
+	alpha_channel_unit: ChannelUnit,
+	precision: u8,
+) -> String {
+	let lightness_str = format_number(color.l, precision);
+	let a_str = format_number(color.a, precision);
+	let b_str = format_number(color.b, precision);
+	let alpha_str_opt = format_alpha_value_conditionally(
+		&(*color).into_color(),
+		alpha_channel_unit,
+		omit_alpha_channel,
+		precision,
+	);
+
+	alpha_str_opt.map_or_else(
+		|| format!("oklab({} {} {})", &lightness_str, &a_str, &b_str),
+		|alpha_str| {
+			format!(
+				"oklab({} {} {} / {})",
+				&lightness_str, &a_str, &b_str, &alpha_str
+			)
+		},
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn to_oklab_function_str_omit_alpha_channel_opaque() {
+		let color: Oklaba = Oklaba::new(0.5, 0.1, -0.05, 1.0);
+
+		let result =
+			to_oklab_function_str(&color, OmitAlphaChannel::IfOpaque, ChannelUnit::Percentage);
+		assert_eq!(result, "oklab(0.5 0.1 -0.05)");
+	}
+
+	#[test]
+	fn to_oklab_function_str_omit_alpha_channel_non_opaque() {
+		let color: Oklaba = Oklaba::new(0.5, 0.1, -0.05, 0.8);
+
+		let result =
+			to_oklab_function_str(&color, OmitAlphaChannel::IfOpaque, ChannelUnit::Percentage);
+		assert_eq!(result, "oklab(0.5 0.1 -0.05 / 80%)");
+	}
+
+	#[test]
+	fn to_oklab_function_str_omit_alpha_never() {
+		let color: Oklaba = Oklaba::new(0.5, 0.1, -0.05, 1.0);
+
+		let result =
+			to_oklab_function_str(&color, OmitAlphaChannel::Never, ChannelUnit::Percentage);
+		assert_eq!(result, "oklab(0.5 0.1 -0.05 / 100%)");
+	}
+
+	#[test]
+	fn to_oklab_function_str_number_alpha_channel() {
+		let color: Oklaba = Oklaba::new(0.5, 0.1, -0.05, 1.0);
+
+		let result = to_oklab_function_str(&color, OmitAlphaChannel::Never, ChannelUnit::Number);
+		assert_eq!(result, "oklab(0.5 0.1 -0.05 / 1)");
+	}
+}
@@ -2,12 +2,12 @@ use palette::Srgba;
 
 use crate::to_str::common::format_alpha_value_conditionally;
 use crate::to_str::css_types::{format_number, format_percentage};
-use crate::to_str::{ChannelUnit, OmitAlphaChannel};
+use crate::to_str::{ChannelUnit, OmitAlphaChannel, DEFAULT_DECIMAL_PRECISION};
 
-fn format_color_channel(color_channel: f32, unit: ChannelUnit) -> String {
+fn format_color_channel(color_channel: f32, unit: ChannelUnit, precision: u8) -> String {
 	match unit {
-		ChannelUnit::Number => format_number(color_channel * 255.0),
-		ChannelUnit::Percentage => format_percentage(color_channel),
+		ChannelUnit::Number => format_number(color_channel * 255.0, precision),
+		ChannelUnit::Percentage => format_percentage(color_channel, precision),
 	}
 }
 
@@ -19,11 +19,28 @@ pub fn to_rgb_function_str(
 	color_channel_unit: ChannelUnit,
 	alpha_channel_unit: ChannelUnit,
 ) -> String {
-	let red_str = format_color_channel(color.red, color_channel_unit);
-	let green_str = format_color_channel(color.green, color_channel_unit);
-	let blue_str = format_color_channel(color.blue, color_channel_unit);
+	to_rgb_function_str_with_precision(
+		color,
+		omit_alpha_channel,
+		color_channel_unit,
+		alpha_channel_unit,
+		DEFAULT_DECIMAL_PRECISION,
+	)
+}
+
+/// Like [`to_rgb_function_str`], but with at most `precision` decimal places for each value.
+pub fn to_rgb_function_str_with_precision(
+	color: &Srgba,
+	omit_alpha_channel: OmitAlphaChannel,
+	color_channel_unit: ChannelUnit,
+	alpha_channel_unit: ChannelUnit,
+	precision: u8,
+) -> String {
+	let red_str = format_color_channel(color.red, color_channel_unit, precision);
+	let green_str = format_color_channel(color.green, color_channel_unit, precision);
+	let blue_str = format_color_channel(color.blue, color_channel_unit, precision);
 	let alpha_str_opt =
-		format_alpha_value_conditionally(color, alpha_channel_unit, omit_alpha_channel);
+		format_alpha_value_conditionally(color, alpha_channel_unit, omit_alpha_channel, precision);
 
 	alpha_str_opt.map_or_else(
 		|| format!("rgb({} {} {})", &red_str, &green_str, &blue_str),
@@ -36,6 +53,37 @@ pub fn to_rgb_function_str(
 	)
 }
 
+/// Creates a legacy CSS-style RGB function string for this color, using comma-separated values
+/// and `rgba` if the alpha channel is included (e.g. `rgba(255, 128, 0, 0.5)`).
+/// Useful for older tools not supporting the modern syntax.
+/// For details see the [CSS color specification](https://www.w3.org/TR/css-color-4/#rgb-functions).
+pub fn to_rgb_legacy_function_str(color: &Srgba, omit_alpha_channel: OmitAlphaChannel) -> String {
+	to_rgb_legacy_function_str_with_precision(color, omit_alpha_channel, DEFAULT_DECIMAL_PRECISION)
+}
+
+/// Like [`to_rgb_legacy_function_str`], but with at most `precision` decimal places for each value.
+pub fn to_rgb_legacy_function_str_with_precision(
+	color: &Srgba,
+	omit_alpha_channel: OmitAlphaChannel,
+	precision: u8,
+) -> String {
+	let red_str = format_color_channel(color.red, ChannelUnit::Number, precision);
+	let green_str = format_color_channel(color.green, ChannelUnit::Number, precision);
+	let blue_str = format_color_channel(color.blue, ChannelUnit::Number, precision);
+	let alpha_str_opt =
+		format_alpha_value_conditionally(color, ChannelUnit::Number, omit_alpha_channel, precision);
+
+	alpha_str_opt.map_or_else(
+		|| format!("rgb({}, {}, {})", &red_str, &green_str, &blue_str),
+		|alpha_str| {
+			format!(
+				"rgba({}, {}, {}, {})",
+				&red_str, &green_str, &blue_str, &alpha_str
+			)
+		},
+	)
+}
+
 #[cfg(test)]
 mod tests {
 	use palette::Srgba;
@@ -68,6 +116,40 @@ mod tests {
 		assert_eq!(result, "rgb(128 255 0 / 0%)");
 	}
 
+	#[test]
+	fn to_rgb_function_str_omit_alpha_channel_rounds_to_opaque() {
+		let color = Srgba::new(0.0, 1.0, 0.0, 0.999);
+
+		let result = to_rgb_function_str(
+			&color,
+			OmitAlphaChannel::IfRoundsToOpaque,
+			ChannelUnit::Number,
+			ChannelUnit::Number,
+		);
+		assert_eq!(result, "rgb(0 255 0)");
+
+		let result = to_rgb_function_str(
+			&color,
+			OmitAlphaChannel::IfOpaque,
+			ChannelUnit::Number,
+			ChannelUnit::Number,
+		);
+		assert_eq!(result, "rgb(0 255 0 / 1)");
+	}
+
+	#[test]
+	fn to_rgb_function_str_omit_alpha_channel_nearly_opaque() {
+		let color = Srgba::new(0.0, 1.0, 0.0, 0.9999999);
+
+		let result = to_rgb_function_str(
+			&color,
+			OmitAlphaChannel::IfOpaque,
+			ChannelUnit::Number,
+			ChannelUnit::Number,
+		);
+		assert_eq!(result, "rgb(0 255 0)");
+	}
+
 	#[test]
 	fn to_rgb_function_str_omit_alpha_never() {
 		let color: Srgba = Srgba::<u8>::new(128, 255, 0, 255).into_format();
@@ -133,6 +215,34 @@ mod tests {
 		assert_eq!(result, "rgb(0.5% 100% 0%)");
 	}
 
+	#[test]
+	fn to_rgb_function_str_with_precision_integer() {
+		let color: Srgba = Srgba::<f32>::new(1f32 / 512f32, 1f32, 0f32, 0.5f32);
+
+		let result = to_rgb_function_str_with_precision(
+			&color,
+			OmitAlphaChannel::IfOpaque,
+			ChannelUnit::Number,
+			ChannelUnit::Percentage,
+			0,
+		);
+		assert_eq!(result, "rgb(1 255 0 / 50%)");
+	}
+
+	#[test]
+	fn to_rgb_function_str_with_precision_decimals() {
+		let color: Srgba = Srgba::<f32>::new(1f32 / 3f32, 1f32, 0f32, 1f32);
+
+		let result = to_rgb_function_str_with_precision(
+			&color,
+			OmitAlphaChannel::IfOpaque,
+			ChannelUnit::Percentage,
+			ChannelUnit::Number,
+			4,
+		);
+		assert_eq!(result, "rgb(33.3334% 100% 0%)");
+	}
+
 	#[test]
 	fn to_rgb_function_str_number_alpha_channel() {
 		let color: Srgba = Srgba::<u8>::new(0, 255, 0, 255).into_format();
@@ -158,4 +268,28 @@ mod tests {
 		);
 		assert_eq!(result, "rgb(0% 100% 0% / 100%)");
 	}
+
+	#[test]
+	fn to_rgb_legacy_function_str_omit_alpha_channel_opaque() {
+		let color: Srgba = Srgba::<u8>::new(128, 255, 0, 255).into_format();
+
+		let result = to_rgb_legacy_function_str(&color, OmitAlphaChannel::IfOpaque);
+		assert_eq!(result, "rgb(128, 255, 0)");
+	}
+
+	#[test]
+	fn to_rgb_legacy_function_str_omit_alpha_channel_non_opaque() {
+		let color: Srgba = Srgba::new(0.0, 1.0, 0.0, 0.5);
+
+		let result = to_rgb_legacy_function_str(&color, OmitAlphaChannel::IfOpaque);
+		assert_eq!(result, "rgba(0, 255, 0, 0.5)");
+	}
+
+	#[test]
+	fn to_rgb_legacy_function_str_omit_alpha_never() {
+		let color: Srgba = Srgba::<u8>::new(128, 255, 0, 255).into_format();
+
+		let result = to_rgb_legacy_function_str(&color, OmitAlphaChannel::Never);
+		assert_eq!(result, "rgba(128, 255, 0, 1)");
+	}
 }
@@ -1,7 +1,8 @@
+use core::fmt;
+
 use palette::Srgba;
 
 use crate::to_str::OmitAlphaChannel;
-use crate::util::is_opaque;
 
 /// Represents the case of hexadecimal letters.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -49,13 +50,12 @@ pub fn to_rgb_hex_str(
 	let mut green_str = format_hex(color.green);
 	let mut blue_str = format_hex(color.blue);
 
-	let mut alpha_str_opt =
-		if is_opaque(&color.into_format()) && omit_alpha_channel == OmitAlphaChannel::IfOpaque {
-			None
-		} else {
-			let alpha_str = format_hex(color.alpha);
-			Some(alpha_str)
-		};
+	let mut alpha_str_opt = if omit_alpha_channel.omits(&color.into_format()) {
+		None
+	} else {
+		let alpha_str = format_hex(color.alpha);
+		Some(alpha_str)
+	};
 
 	if shorthand_notation == ShorthandNotation::IfPossible
 		&& can_shorthand_hexadecimal_channel(&red_str)
@@ -90,6 +90,33 @@ pub fn to_rgb_hex_str(
 	}
 }
 
+/// Wrapper allowing a color to be used with the `{:x}` and `{:X}` format specifiers.
+/// Formats as full hex notation (e.g. `#ff8000`), with the alpha channel only if it is not opaque.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct RgbHex(pub Srgba<u8>);
+
+impl fmt::LowerHex for RgbHex {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&to_rgb_hex_str(
+			&self.0,
+			OmitAlphaChannel::IfOpaque,
+			ShorthandNotation::Never,
+			LetterCase::Lowercase,
+		))
+	}
+}
+
+impl fmt::UpperHex for RgbHex {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&to_rgb_hex_str(
+			&self.0,
+			OmitAlphaChannel::IfOpaque,
+			ShorthandNotation::Never,
+			LetterCase::Uppercase,
+		))
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -133,6 +160,19 @@ mod tests {
 		assert_eq!(result, "#11FF0AFF");
 	}
 
+	#[test]
+	fn to_rgb_hex_str_omit_alpha_channel_rounds_to_opaque() {
+		let color: Srgba<u8> = Srgba::<f32>::new(0.2, 1.0, 0.0, 0.9999999).into_format();
+
+		let result = to_rgb_hex_str(
+			&color,
+			OmitAlphaChannel::IfRoundsToOpaque,
+			ShorthandNotation::Never,
+			LetterCase::Uppercase,
+		);
+		assert_eq!(result, "#33FF00");
+	}
+
 	#[test]
 	fn to_rgb_hex_str_shorthand_notation_possible() {
 		let color = Srgba::<u8>::new(0x11, 0xff, 0x00, 0xff);
@@ -223,4 +263,25 @@ mod tests {
 		);
 		assert_eq!(result, "#11ff0a");
 	}
+
+	#[test]
+	fn rgb_hex_lower_hex() {
+		let color = RgbHex(Srgba::<u8>::new(0x11, 0xff, 0x0a, 0xff));
+
+		assert_eq!(format!("{:x}", color), "#11ff0a");
+	}
+
+	#[test]
+	fn rgb_hex_upper_hex() {
+		let color = RgbHex(Srgba::<u8>::new(0x11, 0xff, 0x0a, 0xff));
+
+		assert_eq!(format!("{:X}", color), "#11FF0A");
+	}
+
+	#[test]
+	fn rgb_hex_non_opaque() {
+		let color = RgbHex(Srgba::<u8>::new(0x11, 0xff, 0x0a, 0x80));
+
+		assert_eq!(format!("{:x}", color), "#11ff0a80");
+	}
 }
@@ -1,4 +1,32 @@
-use palette::{Srgba, WithAlpha};
+use std::hash::{Hash, Hasher};
+
+use palette::rgb::channels::Argb;
+use palette::rgb::Rgb;
+use palette::{
+	Clamp, FromColorUnclamped, Hsl, Hwb, IntoColor, Lab, Lch, LinSrgb, Oklab, Oklch, Srgba,
+	WithAlpha,
+};
+
+use crate::contrast::relative_luminance;
+use crate::gamut::{is_in_srgb_gamut, RangeError};
+
+/// Opaque black.
+pub const BLACK: Srgba = Srgba::new(0.0, 0.0, 0.0, 1.0);
+
+/// Opaque white.
+pub const WHITE: Srgba = Srgba::new(1.0, 1.0, 1.0, 1.0);
+
+/// Fully transparent black.
+pub const TRANSPARENT: Srgba = Srgba::new(0.0, 0.0, 0.0, 0.0);
+
+/// Opaque red.
+pub const RED: Srgba = Srgba::new(1.0, 0.0, 0.0, 1.0);
+
+/// Opaque green.
+pub const GREEN: Srgba = Srgba::new(0.0, 1.0, 0.0, 1.0);
+
+/// Opaque blue.
+pub const BLUE: Srgba = Srgba::new(0.0, 0.0, 1.0, 1.0);
 
 /// Checks if the color is fully opaque
 // TODO: allow any `Alpha` type
@@ -6,8 +34,295 @@ pub fn is_opaque(srgba: &Srgba) -> bool {
 	srgba.eq(&srgba.with_alpha(1.0))
 }
 
+/// Default tolerance for [`is_effectively_opaque`], covering floating point errors from conversions.
+pub const OPAQUE_TOLERANCE: f32 = f32::EPSILON * 4.0;
+
+/// Checks if the alpha of the color is at most `tolerance` away from 1.0,
+/// e.g. `0.9999999` from parsing `rgb(255 255 255 / 100%)`.
+pub fn is_effectively_opaque(srgba: &Srgba, tolerance: f32) -> bool {
+	(1.0 - srgba.alpha).abs() <= tolerance
+}
+
+/// Checks if the color is dark, i.e. its relative luminance is below 0.179.
+/// Text on a dark color is more readable in white, while text on a light color is more readable in black.
+pub fn is_dark(color: &Rgb) -> bool {
+	relative_luminance(color) < 0.179
+}
+
+/// Checks if the color is light, see [`is_dark`].
+pub fn is_light(color: &Rgb) -> bool {
+	!is_dark(color)
+}
+
+/// Maximum difference between channels for a color to still be considered achromatic.
+const ACHROMATIC_TOLERANCE: f32 = 1e-4;
+
+/// Checks if the color is achromatic (a shade of gray), i.e. if all channels are equal
+/// within a small tolerance for floating point errors.
+/// The hue of achromatic colors, e.g. in HSL, is meaningless.
+pub fn is_achromatic(color: &Rgb) -> bool {
+	achromatic_value(color).is_some()
+}
+
+/// Gets the common channel value if the color is achromatic, see [`is_achromatic`].
+pub fn achromatic_value(color: &Rgb) -> Option<f32> {
+	let max = color.red.max(color.green).max(color.blue);
+	let min = color.red.min(color.green).min(color.blue);
+	(max - min <= ACHROMATIC_TOLERANCE).then_some(color.red)
+}
+
+/// Checks if the color is dark, ignoring alpha. See [`is_dark`].
+pub fn is_dark_srgba(srgba: &Srgba) -> bool {
+	is_dark(&srgba.without_alpha())
+}
+
+/// Gets the channels in RGBA order.
+/// Suitable for graphics libraries (e.g. wgpu) accepting colors as `[f32; 4]`.
+pub fn to_channels(srgba: &Srgba) -> [f32; 4] {
+	palette::cast::into_array(*srgba)
+}
+
+/// Gets the channels in RGBA order as tuple. See [`to_channels`].
+pub fn to_rgba_f32(srgba: &Srgba) -> (f32, f32, f32, f32) {
+	srgba.into_components()
+}
+
+/// Gets the channels in RGB order as tuple, dropping alpha.
+pub fn to_rgb_f32(srgba: &Srgba) -> (f32, f32, f32) {
+	srgba.without_alpha().into_components()
+}
+
+/// Applies `f` to each channel including alpha, returning the resulting color.
+/// For example, `map_channels(&color, |channel| channel.powf(2.2))` applies a gamma curve.
+pub fn map_channels<F>(srgba: &Srgba, f: F) -> Srgba
+where
+	F: Fn(f32) -> f32,
+{
+	palette::cast::from_array(to_channels(srgba).map(f))
+}
+
+/// Gets the channels rounded to 8 bit in RGBA order.
+/// Suitable as a stable key, e.g. for hashing, as colors equal in 8 bit precision have the same bytes.
+pub fn to_channel_bytes(srgba: &Srgba) -> [u8; 4] {
+	let converted: Srgba<u8> = srgba.into_format();
+	[
+		converted.red,
+		converted.green,
+		converted.blue,
+		converted.alpha,
+	]
+}
+
+/// Gets the channels in 8 bit in RGBA order, if they can be represented without loss of precision.
+pub fn to_channel_bytes_exact(srgba: &Srgba) -> Option<[u8; 4]> {
+	let converted: Srgba<u8> = srgba.into_format();
+	if converted.into_format::<f32, f32>() == *srgba {
+		Some(to_channel_bytes(srgba))
+	} else {
+		None
+	}
+}
+
+/// Packs the channels rounded to 8 bit into a `u32` in `0xAARRGGBB` order,
+/// as commonly used for textures.
+pub fn to_u32_argb(srgba: &Srgba) -> u32 {
+	let converted: Srgba<u8> = srgba.into_format();
+	converted.into_u32::<Argb>()
+}
+
+/// Unpacks a color from a `u32` in `0xAARRGGBB` order, see [`to_u32_argb`].
+pub fn from_u32_argb(argb: u32) -> Srgba {
+	Srgba::<u8>::from_u32::<Argb>(argb).into_format()
+}
+
+/// Applies the inverse sRGB transfer function to each channel, resulting in linear light values.
+/// Channels below 0.04045 are divided by 12.92, all others follow the power curve.
+pub fn to_linear_srgb(color: &Rgb) -> LinSrgb {
+	color.into_linear()
+}
+
+/// Applies the sRGB transfer function to each channel of a color with linear light values,
+/// see [`to_linear_srgb`].
+pub fn from_linear_srgb(linear: &LinSrgb) -> Rgb {
+	Rgb::from_linear(*linear)
+}
+
+/// Gets the Oklab components as `(L, a, b)`.
+pub fn to_oklab_components(color: &Rgb) -> (f32, f32, f32) {
+	let oklab: Oklab = (*color).into_color();
+	oklab.into_components()
+}
+
+/// Gets the Oklch components as `(L, C, H)`, with the hue in degrees from 0 to 360.
+pub fn to_oklch_components(color: &Rgb) -> (f32, f32, f32) {
+	let oklch: Oklch = (*color).into_color();
+	(oklch.l, oklch.chroma, oklch.hue.into_positive_degrees())
+}
+
+/// Gets the HSL components as `(H, S, L)`, with the hue in degrees from 0 to 360
+/// and saturation and lightness in the range 0 to 1.
+pub fn to_hsl_components(color: &Rgb) -> (f32, f32, f32) {
+	let hsl: Hsl = (*color).into_color();
+	(
+		hsl.hue.into_positive_degrees(),
+		hsl.saturation,
+		hsl.lightness,
+	)
+}
+
+/// Gets the HWB components as `(H, W, B)`, with the hue in degrees from 0 to 360
+/// and whiteness and blackness in the range 0 to 1.
+pub fn to_hwb_components(color: &Rgb) -> (f32, f32, f32) {
+	let hwb: Hwb = (*color).into_color();
+	(
+		hwb.hue.into_positive_degrees(),
+		hwb.whiteness,
+		hwb.blackness,
+	)
+}
+
+/// Converts the color to sRGB, failing if it is outside of the sRGB gamut.
+/// Deviations caused by floating point errors are clamped.
+fn from_color_checked<C>(color: C) -> Result<Rgb, RangeError>
+where
+	Rgb: FromColorUnclamped<C>,
+{
+	let rgb = Rgb::from_color_unclamped(color);
+	if is_in_srgb_gamut(&rgb) {
+		Ok(rgb.clamp())
+	} else {
+		Err(RangeError(rgb))
+	}
+}
+
+/// Creates a color from Oklab components.
+///
+/// # Errors
+/// - If the color is outside of the sRGB gamut, see [`from_oklab_clamped`].
+pub fn from_oklab(l: f32, a: f32, b: f32) -> Result<Rgb, RangeError> {
+	from_color_checked(Oklab::new(l, a, b))
+}
+
+/// Creates a color from Oklab components, clamping it into the sRGB gamut.
+pub fn from_oklab_clamped(l: f32, a: f32, b: f32) -> Rgb {
+	Rgb::from_color_unclamped(Oklab::new(l, a, b)).clamp()
+}
+
+/// Creates a color from Oklch components, with the hue in degrees.
+///
+/// # Errors
+/// - If the color is outside of the sRGB gamut, see [`from_oklch_clamped`].
+pub fn from_oklch(l: f32, chroma: f32, hue: f32) -> Result<Rgb, RangeError> {
+	from_color_checked(Oklch::new(l, chroma, hue))
+}
+
+/// Creates a color from Oklch components, with the hue in degrees, clamping it into the sRGB gamut.
+pub fn from_oklch_clamped(l: f32, chroma: f32, hue: f32) -> Rgb {
+	Rgb::from_color_unclamped(Oklch::new(l, chroma, hue)).clamp()
+}
+
+/// Creates a color from HSL components, with the hue in degrees
+/// and saturation and lightness in the range 0 to 1.
+///
+/// # Errors
+/// - If the color is outside of the sRGB gamut, i.e. saturation or lightness are out of range.
+pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Result<Rgb, RangeError> {
+	from_color_checked(Hsl::new(hue, saturation, lightness))
+}
+
+/// Creates a color from HSL components like [`from_hsl`], clamping it into the sRGB gamut.
+pub fn from_hsl_clamped(hue: f32, saturation: f32, lightness: f32) -> Rgb {
+	Rgb::from_color_unclamped(Hsl::new(hue, saturation, lightness)).clamp()
+}
+
+/// Creates a color from HWB components, with the hue in degrees
+/// and whiteness and blackness in the range 0 to 1.
+///
+/// # Errors
+/// - If the color is outside of the sRGB gamut, i.e. whiteness or blackness are out of range.
+pub fn from_hwb(hue: f32, whiteness: f32, blackness: f32) -> Result<Rgb, RangeError> {
+	from_color_checked(Hwb::new(hue, whiteness, blackness))
+}
+
+/// Creates a color from HWB components like [`from_hwb`], clamping it into the sRGB gamut.
+pub fn from_hwb_clamped(hue: f32, whiteness: f32, blackness: f32) -> Rgb {
+	Rgb::from_color_unclamped(Hwb::new(hue, whiteness, blackness)).clamp()
+}
+
+/// Creates a color from CIE Lab components, with the lightness in the range 0 to 100.
+///
+/// # Errors
+/// - If the color is outside of the sRGB gamut, see [`from_lab_clamped`].
+pub fn from_lab(l: f32, a: f32, b: f32) -> Result<Rgb, RangeError> {
+	from_color_checked(Lab::new(l, a, b))
+}
+
+/// Creates a color from CIE Lab components, clamping it into the sRGB gamut.
+pub fn from_lab_clamped(l: f32, a: f32, b: f32) -> Rgb {
+	Rgb::from_color_unclamped(Lab::new(l, a, b)).clamp()
+}
+
+/// Creates a color from CIE Lch components, with the lightness in the range 0 to 100 and the hue in degrees.
+///
+/// # Errors
+/// - If the color is outside of the sRGB gamut, see [`from_lch_clamped`].
+pub fn from_lch(l: f32, chroma: f32, hue: f32) -> Result<Rgb, RangeError> {
+	from_color_checked(Lch::new(l, chroma, hue))
+}
+
+/// Creates a color from CIE Lch components, clamping it into the sRGB gamut.
+pub fn from_lch_clamped(l: f32, chroma: f32, hue: f32) -> Rgb {
+	Rgb::from_color_unclamped(Lch::new(l, chroma, hue)).clamp()
+}
+
+/// Wrapper making a color usable as key in a `HashMap` or `HashSet` without losing precision.
+///
+/// Channels are compared by their canonical bit representation: `0.0` and `-0.0` are equal,
+/// and unlike with [`PartialEq`] on [`Srgba`], all NaN values are equal to each other.
+/// Colors equal under [`PartialEq`] on [`Srgba`] are always equal keys with the same hash.
+/// If equality in 8 bit precision is sufficient, [`to_channel_bytes`] can be used instead.
+#[derive(Debug, Copy, Clone)]
+pub struct ColorKey(pub Srgba);
+
+fn canonical_bits(channel: f32) -> u32 {
+	if channel.is_nan() {
+		f32::NAN.to_bits()
+	} else if channel == 0.0 {
+		0.0_f32.to_bits()
+	} else {
+		channel.to_bits()
+	}
+}
+
+impl ColorKey {
+	fn canonical_channel_bits(&self) -> [u32; 4] {
+		[
+			canonical_bits(self.0.red),
+			canonical_bits(self.0.green),
+			canonical_bits(self.0.blue),
+			canonical_bits(self.0.alpha),
+		]
+	}
+}
+
+impl PartialEq for ColorKey {
+	fn eq(&self, other: &Self) -> bool {
+		self.canonical_channel_bits() == other.canonical_channel_bits()
+	}
+}
+
+impl Eq for ColorKey {}
+
+impl Hash for ColorKey {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.canonical_channel_bits().hash(state);
+	}
+}
+
 #[cfg(test)]
 mod tests {
+	use std::collections::HashSet;
+
 	use palette::Srgba;
 
 	use super::*;
@@ -19,6 +334,22 @@ mod tests {
 		assert!(!is_opaque(&color));
 	}
 
+	#[test]
+	fn is_effectively_opaque_uses_tolerance() {
+		assert!(is_effectively_opaque(
+			&Srgba::new(1.0, 1.0, 1.0, 0.9999999),
+			OPAQUE_TOLERANCE
+		));
+		assert!(!is_effectively_opaque(
+			&Srgba::new(1.0, 1.0, 1.0, 0.999),
+			OPAQUE_TOLERANCE
+		));
+		assert!(is_effectively_opaque(
+			&Srgba::new(1.0, 1.0, 1.0, 0.999),
+			0.01
+		));
+	}
+
 	#[test]
 	fn is_opaque_true_for_opaque() {
 		// 0.2 -> 51 in u8
@@ -26,4 +357,188 @@ mod tests {
 
 		assert!(is_opaque(&color));
 	}
+
+	#[test]
+	fn is_dark_for_dark_color() {
+		let color = Rgb::new(0.0, 0.1, 0.25);
+
+		assert!(is_dark(&color));
+		assert!(!is_light(&color));
+	}
+
+	#[test]
+	fn is_light_for_light_color() {
+		let color = Rgb::new(0.9, 0.85, 1.0);
+
+		assert!(is_light(&color));
+		assert!(!is_dark(&color));
+	}
+
+	#[test]
+	fn is_achromatic_for_gray() {
+		let color = Rgb::new(0.5, 0.50001, 0.5);
+
+		assert!(is_achromatic(&color));
+		assert_eq!(achromatic_value(&color), Some(0.5));
+	}
+
+	#[test]
+	fn is_achromatic_false_for_colored() {
+		let color = Rgb::new(0.5, 0.51, 0.5);
+
+		assert!(!is_achromatic(&color));
+		assert_eq!(achromatic_value(&color), None);
+	}
+
+	#[test]
+	fn is_dark_srgba_ignores_alpha() {
+		let color: Srgba = Srgba::new(0.0, 0.1, 0.25, 0.0);
+
+		assert!(is_dark_srgba(&color));
+	}
+
+	#[test]
+	fn to_channels_in_rgba_order() {
+		let color: Srgba = Srgba::new(1.0, 0.5, 0.25, 0.75);
+
+		assert_eq!(to_channels(&color), [1.0, 0.5, 0.25, 0.75]);
+	}
+
+	#[test]
+	fn to_rgba_f32_in_rgba_order() {
+		let color: Srgba = Srgba::new(1.0, 0.5, 0.25, 0.75);
+
+		assert_eq!(to_rgba_f32(&color), (1.0, 0.5, 0.25, 0.75));
+		assert_eq!(to_rgb_f32(&color), (1.0, 0.5, 0.25));
+	}
+
+	#[test]
+	fn map_channels_maps_all_channels() {
+		let color: Srgba = Srgba::new(1.0, 0.5, 0.25, 0.75);
+
+		assert_eq!(
+			map_channels(&color, |channel| channel / 2.0),
+			Srgba::new(0.5, 0.25, 0.125, 0.375)
+		);
+	}
+
+	#[test]
+	fn to_channel_bytes_rounds() {
+		let color: Srgba = Srgba::new(1.0, 0.5, 0.0, 1.0);
+
+		assert_eq!(to_channel_bytes(&color), [255, 128, 0, 255]);
+	}
+
+	#[test]
+	fn to_channel_bytes_exact_exact() {
+		let color: Srgba = Srgba::<u8>::new(255, 51, 0, 128).into_format();
+
+		assert_eq!(to_channel_bytes_exact(&color), Some([255, 51, 0, 128]));
+	}
+
+	#[test]
+	fn color_key_deduplicates() {
+		let colors = [
+			ColorKey(Srgba::new(1.0, 0.5, 0.0, 1.0)),
+			ColorKey(Srgba::new(1.0, 0.5, -0.0, 1.0)),
+			ColorKey(Srgba::new(1.0, 0.5, 0.0, 0.5)),
+			ColorKey(Srgba::new(f32::NAN, 0.5, 0.0, 0.5)),
+			ColorKey(Srgba::new(-f32::NAN, 0.5, 0.0, 0.5)),
+		];
+
+		assert_eq!(HashSet::from(colors).len(), 3);
+	}
+
+	#[test]
+	fn to_u32_argb_packs() {
+		let color: Srgba = Srgba::<u8>::new(0x60, 0x7F, 0x00, 0x80).into_format();
+
+		assert_eq!(to_u32_argb(&color), 0x80607F00);
+	}
+
+	#[test]
+	fn from_u32_argb_unpacks() {
+		assert_eq!(
+			from_u32_argb(0x80607F00).into_format::<u8, u8>(),
+			Srgba::<u8>::new(0x60, 0x7F, 0x00, 0x80)
+		);
+	}
+
+	#[test]
+	fn to_channel_bytes_exact_lossy() {
+		let color: Srgba = Srgba::new(1.0, 0.5, 0.0, 1.0);
+
+		assert_eq!(to_channel_bytes_exact(&color), None);
+	}
+
+	#[test]
+	fn to_linear_srgb_known_values() {
+		let linear = to_linear_srgb(&Rgb::new(0.0, 0.5, 1.0));
+
+		assert_eq!(linear.red, 0.0);
+		assert!((linear.green - 0.214_041).abs() < 1e-5);
+		assert_eq!(linear.blue, 1.0);
+		// Linear segment of the transfer function.
+		assert!((to_linear_srgb(&Rgb::new(0.04, 0.0, 0.0)).red - 0.04 / 12.92).abs() < 1e-7);
+	}
+
+	#[test]
+	fn from_linear_srgb_round_trip() {
+		let color = Rgb::new(0.02, 0.5, 0.8);
+
+		let round_tripped = from_linear_srgb(&to_linear_srgb(&color));
+
+		assert!((round_tripped.red - color.red).abs() < 1e-5);
+		assert!((round_tripped.green - color.green).abs() < 1e-5);
+		assert!((round_tripped.blue - color.blue).abs() < 1e-5);
+	}
+
+	#[test]
+	fn to_oklab_and_oklch_components_for_red() {
+		let red = Rgb::new(1.0, 0.0, 0.0);
+
+		let (l, a, b) = to_oklab_components(&red);
+		assert!((l - 0.628).abs() < 1e-3);
+		assert!((a - 0.225).abs() < 1e-3);
+		assert!((b - 0.126).abs() < 1e-3);
+
+		let (l, chroma, hue) = to_oklch_components(&red);
+		assert!((l - 0.628).abs() < 1e-3);
+		assert!((chroma - 0.258).abs() < 1e-3);
+		assert!((hue - 29.23).abs() < 0.1);
+	}
+
+	#[test]
+	fn to_hsl_and_hwb_components_for_red() {
+		let red = Rgb::new(1.0, 0.0, 0.0);
+
+		assert_eq!(to_hsl_components(&red), (0.0, 1.0, 0.5));
+		assert_eq!(to_hwb_components(&red), (0.0, 0.0, 0.0));
+	}
+
+	#[test]
+	fn from_oklab_round_trip() {
+		let color = Rgb::new(0.2, 0.5, 0.8);
+		let (l, a, b) = to_oklab_components(&color);
+
+		let round_tripped = from_oklab(l, a, b).unwrap();
+
+		assert!((round_tripped.red - color.red).abs() < 1e-4);
+		assert!((round_tripped.green - color.green).abs() < 1e-4);
+		assert!((round_tripped.blue - color.blue).abs() < 1e-4);
+	}
+
+	#[test]
+	fn from_lch_rejects_out_of_gamut() {
+		assert!(from_lch(60.0, 150.0, 140.0).is_err());
+		assert!(is_in_srgb_gamut(&from_lch_clamped(60.0, 150.0, 140.0)));
+	}
+
+	#[test]
+	fn from_hsl_and_hwb() {
+		assert_eq!(from_hsl(0.0, 1.0, 0.5), Ok(Rgb::new(1.0, 0.0, 0.0)));
+		assert_eq!(from_hwb(0.0, 0.0, 0.0), Ok(Rgb::new(1.0, 0.0, 0.0)));
+		assert!(from_hsl(0.0, 1.0, 1.5).is_err());
+		assert_eq!(from_hsl_clamped(0.0, 1.0, 1.5), Rgb::new(1.0, 1.0, 1.0));
+	}
 }
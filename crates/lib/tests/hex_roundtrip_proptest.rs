@@ -0,0 +1,67 @@
+use palette::Srgba;
+use proptest::prelude::*;
+
+use color_utils::parser::parse_color;
+use color_utils::to_str::{
+	to_rgb_function_str, to_rgb_hex_str, ChannelUnit, LetterCase, OmitAlphaChannel,
+	ShorthandNotation,
+};
+
+fn omit_alpha_channel() -> impl Strategy<Value = OmitAlphaChannel> {
+	prop_oneof![
+		Just(OmitAlphaChannel::Never),
+		Just(OmitAlphaChannel::IfOpaque)
+	]
+}
+
+fn shorthand_notation() -> impl Strategy<Value = ShorthandNotation> {
+	prop_oneof![
+		Just(ShorthandNotation::Never),
+		Just(ShorthandNotation::IfPossible)
+	]
+}
+
+fn letter_case() -> impl Strategy<Value = LetterCase> {
+	prop_oneof![Just(LetterCase::Uppercase), Just(LetterCase::Lowercase)]
+}
+
+fn channel_unit() -> impl Strategy<Value = ChannelUnit> {
+	prop_oneof![Just(ChannelUnit::Number), Just(ChannelUnit::Percentage)]
+}
+
+proptest! {
+	#[test]
+	fn rgb_hex_round_trip(
+		channels: [u8; 4],
+		omit_alpha_channel in omit_alpha_channel(),
+		shorthand_notation in shorthand_notation(),
+		letter_case in letter_case(),
+	) {
+		let color = Srgba::<u8>::from(channels);
+
+		let hex_str = to_rgb_hex_str(&color, omit_alpha_channel, shorthand_notation, letter_case);
+		let parsed = parse_color(&hex_str).unwrap();
+
+		prop_assert_eq!(parsed.into_format::<u8, u8>(), color);
+	}
+
+	#[test]
+	fn rgb_function_round_trip(
+		channels: [u8; 4],
+		omit_alpha_channel in omit_alpha_channel(),
+		color_channel_unit in channel_unit(),
+	) {
+		let color = Srgba::<u8>::from(channels);
+
+		// Alpha numbers are formatted with two decimal places, which is not precise enough for 8 bit.
+		let rgb_function_str = to_rgb_function_str(
+			&color.into_format(),
+			omit_alpha_channel,
+			color_channel_unit,
+			ChannelUnit::Percentage,
+		);
+		let parsed = parse_color(&rgb_function_str).unwrap();
+
+		prop_assert_eq!(parsed.into_format::<u8, u8>(), color);
+	}
+}
@@ -0,0 +1,20 @@
+use palette::Srgba;
+use proptest::prelude::*;
+
+use color_utils::operations::{invert, to_grayscale};
+
+proptest! {
+	#[test]
+	fn invert_twice_is_identity(channels: [u8; 4]) {
+		let color: Srgba = Srgba::<u8>::from(channels).into_format();
+
+		prop_assert_eq!(invert(&invert(&color)).into_format::<u8, u8>(), color.into_format());
+	}
+
+	#[test]
+	fn to_grayscale_of_gray_is_identity(value: u8, alpha: u8) {
+		let color: Srgba = Srgba::<u8>::new(value, value, value, alpha).into_format();
+
+		prop_assert_eq!(to_grayscale(&color).into_format::<u8, u8>(), color.into_format());
+	}
+}